@@ -0,0 +1,862 @@
+// Async Redis Connection wrapper for HarmonyOS NAPI
+//
+// Wraps redis-rs's multiplexed connection. Every method returns a Promise and
+// runs on the tokio runtime, so slow networks never block the ArkTS thread.
+// The multiplexed connection is cheap to clone and pipelines concurrent
+// requests over a single socket.
+//
+// Blocking commands (BLPOP, BRPOP, BLMOVE, BZPOPMIN, XREAD BLOCK) would hold up every other
+// request queued on the shared socket, so each one runs on its own connection
+// whose response timeout covers the block duration.
+//
+// Commands fail with a Timeout error when no reply arrives within the
+// connection's command timeout (500 ms unless configured otherwise).
+//
+// Long-running methods accept an optional CancellationToken; they return a
+// Promise built with `spawn_cancellable` instead of being `async fn`s, since
+// an async method cannot borrow the token object.
+
+use napi_derive_ohos::napi;
+use napi_ohos::bindgen_prelude::*;
+use redis::aio::MultiplexedConnection;
+use redis::{AsyncCommands, AsyncConnectionConfig, Client, Direction};
+use std::time::Duration;
+
+use crate::async_pipeline::AsyncRedisPipeline;
+use crate::cancel::{spawn_cancellable, CancellationToken};
+use crate::connection::{command_from_args, pair_args, redis_value_to_json_value, xread_cmd, StreamReadReply};
+use crate::error::redis_error;
+use crate::types::{
+    BlockingPopResult, BlockingZsetPopResult, ListDirection, PushMessage, RedisValue, StreamReadResult,
+};
+
+/// Extra time allowed for the reply of a blocking command past its timeout
+const BLOCKING_REPLY_GRACE: Duration = Duration::from_secs(1);
+
+/// Async Redis Connection for HarmonyOS
+///
+/// Created with `RedisClient.getAsyncConnection()`.
+///
+/// # Example (ArkTS)
+/// ```typescript
+/// const conn = await client.getAsyncConnection();
+///
+/// await conn.set("greeting", "hello");
+/// const value = await conn.get("greeting");
+///
+/// // Requests issued concurrently share the same socket
+/// const [a, b] = await Promise.all([conn.get("a"), conn.get("b")]);
+/// ```
+#[napi]
+pub struct AsyncRedisConnection {
+    client: Client,
+    /// Connect timeout, resolver and response timeout of this connection,
+    /// reused for the dedicated connections of blocking commands
+    config: AsyncConnectionConfig,
+    inner: MultiplexedConnection,
+}
+
+impl AsyncRedisConnection {
+    pub(crate) fn new(client: Client, config: AsyncConnectionConfig, conn: MultiplexedConnection) -> Self {
+        AsyncRedisConnection { client, config, inner: conn }
+    }
+
+    /// Clone of the connection handle for one request
+    fn conn(&self) -> MultiplexedConnection {
+        self.inner.clone()
+    }
+}
+
+/// Dedicated connection for a blocking command
+///
+/// Opened with the settings of the parent connection (`config`), except for
+/// the response timeout. A timeout of 0 blocks forever, so the reply has no
+/// deadline either.
+async fn blocking_conn(
+    client: Client,
+    config: AsyncConnectionConfig,
+    timeout_seconds: f64,
+) -> Result<MultiplexedConnection> {
+    if !timeout_seconds.is_finite() || timeout_seconds < 0.0 {
+        return Err(napi_ohos::Error::new(
+            Status::InvalidArg,
+            format!("Invalid blocking timeout: {}", timeout_seconds),
+        ));
+    }
+
+    let response_timeout =
+        (timeout_seconds > 0.0).then(|| Duration::from_secs_f64(timeout_seconds) + BLOCKING_REPLY_GRACE);
+    let config = config.set_response_timeout(response_timeout);
+    client
+        .get_multiplexed_async_connection_with_config(&config)
+        .await
+        .map_err(|e| redis_error("Connect", e))
+}
+
+/// Convert a RESP3 push frame for `getPushConnection()` callbacks
+pub(crate) fn push_message(info: &redis::PushInfo) -> Result<PushMessage> {
+    let keys = match (&info.kind, info.data.first()) {
+        (redis::PushKind::Invalidate, Some(redis::Value::Array(keys))) => Some(
+            keys.iter()
+                .filter_map(|key| redis::from_redis_value_ref::<String>(key).ok())
+                .collect(),
+        ),
+        _ => None,
+    };
+
+    let data = info
+        .data
+        .iter()
+        .map(redis_value_to_json_value)
+        .collect::<Result<Vec<_>>>()?;
+    Ok(PushMessage {
+        kind: info.kind.to_string(),
+        data: serde_json::to_string(&data)
+            .map_err(|e| napi_ohos::Error::from_reason(format!("JSON serialization failed: {}", e)))?,
+        keys,
+    })
+}
+
+/// Validate a command timeout given in milliseconds
+pub(crate) fn command_timeout(timeout_ms: u32) -> Result<Duration> {
+    if timeout_ms == 0 {
+        return Err(napi_ohos::Error::new(
+            Status::InvalidArg,
+            "Command timeout must be at least 1 ms",
+        ));
+    }
+    Ok(Duration::from_millis(timeout_ms as u64))
+}
+
+impl From<ListDirection> for Direction {
+    fn from(direction: ListDirection) -> Self {
+        match direction {
+            ListDirection::Left => Direction::Left,
+            ListDirection::Right => Direction::Right,
+        }
+    }
+}
+
+#[napi]
+impl AsyncRedisConnection {
+    // ==================== String Commands ====================
+
+    /// SET command - Set a string value
+    #[napi]
+    pub async fn set(&self, key: String, value: String) -> Result<()> {
+        self.conn()
+            .set(key, value)
+            .await
+            .map_err(|e| redis_error("SET", e))
+    }
+
+    /// GET command - Get a string value
+    ///
+    /// # Returns
+    /// The value as a string, or null if key doesn't exist
+    #[napi]
+    pub async fn get(&self, key: String) -> Result<Option<String>> {
+        self.conn().get(key).await.map_err(|e| redis_error("GET", e))
+    }
+
+    /// SETEX command - Set key with expiration in seconds
+    #[napi]
+    pub async fn setex(&self, key: String, value: String, seconds: u32) -> Result<()> {
+        self.conn()
+            .set_ex(key, value, seconds as u64)
+            .await
+            .map_err(|e| redis_error("SETEX", e))
+    }
+
+    /// SETNX command - Set key only if it doesn't exist
+    ///
+    /// # Returns
+    /// true if key was set, false if key already exists
+    #[napi]
+    pub async fn setnx(&self, key: String, value: String) -> Result<bool> {
+        self.conn()
+            .set_nx(key, value)
+            .await
+            .map_err(|e| redis_error("SETNX", e))
+    }
+
+    /// MGET command - Get multiple values
+    ///
+    /// # Returns
+    /// Array of values (null for non-existent keys)
+    ///
+    /// # Arguments
+    /// * `keys` - Keys to read
+    /// * `token` - Optional CancellationToken that aborts the request
+    #[napi(ts_return_type = "Promise<Array<string | null>>")]
+    pub fn mget<'env>(
+        &self,
+        env: &'env Env,
+        keys: Vec<String>,
+        token: Option<&CancellationToken>,
+    ) -> Result<PromiseRaw<'env, Vec<Option<String>>>> {
+        let mut conn = self.conn();
+        spawn_cancellable(env, token, "MGET", async move {
+            redis::cmd("MGET")
+                .arg(keys)
+                .query_async(&mut conn)
+                .await
+                .map_err(|e| redis_error("MGET", e))
+        })
+    }
+
+    /// MSET command - Set multiple key-value pairs
+    ///
+    /// # Arguments
+    /// * `pairs` - Array of [key, value] pairs, or an object mapping keys to values
+    #[napi]
+    pub async fn mset(&self, pairs: Either<Vec<Vec<String>>, std::collections::HashMap<String, String>>) -> Result<()> {
+        let mut cmd = redis::cmd("MSET");
+        pair_args(&mut cmd, &pairs, "MSET")?;
+
+        cmd.query_async(&mut self.conn())
+            .await
+            .map_err(|e| redis_error("MSET", e))
+    }
+
+    /// INCR command - Increment integer value by 1
+    ///
+    /// # Returns
+    /// The value after increment
+    #[napi]
+    pub async fn incr(&self, key: String) -> Result<i64> {
+        self.conn().incr(key, 1).await.map_err(|e| redis_error("INCR", e))
+    }
+
+    /// INCRBY command - Increment integer value by amount
+    ///
+    /// # Returns
+    /// The value after increment
+    #[napi]
+    pub async fn incr_by(&self, key: String, delta: i64) -> Result<i64> {
+        self.conn()
+            .incr(key, delta)
+            .await
+            .map_err(|e| redis_error("INCRBY", e))
+    }
+
+    /// DECR command - Decrement integer value by 1
+    ///
+    /// # Returns
+    /// The value after decrement
+    #[napi]
+    pub async fn decr(&self, key: String) -> Result<i64> {
+        self.conn().decr(key, 1).await.map_err(|e| redis_error("DECR", e))
+    }
+
+    // ==================== Key Commands ====================
+
+    /// DEL command - Delete one or more keys
+    ///
+    /// # Returns
+    /// Number of keys deleted
+    #[napi]
+    pub async fn del(&self, keys: Vec<String>) -> Result<i64> {
+        self.conn().del(keys).await.map_err(|e| redis_error("DEL", e))
+    }
+
+    /// UNLINK command - Delete keys, reclaiming their memory in the background
+    ///
+    /// # Returns
+    /// Number of keys unlinked
+    #[napi]
+    pub async fn unlink(&self, keys: Vec<String>) -> Result<i64> {
+        self.conn().unlink(keys).await.map_err(|e| redis_error("UNLINK", e))
+    }
+
+    /// EXISTS command - Check if key exists
+    #[napi]
+    pub async fn exists(&self, key: String) -> Result<bool> {
+        self.conn()
+            .exists(key)
+            .await
+            .map_err(|e| redis_error("EXISTS", e))
+    }
+
+    /// EXPIRE command - Set key expiration in seconds
+    ///
+    /// # Returns
+    /// true if timeout was set, false if key doesn't exist
+    #[napi]
+    pub async fn expire(&self, key: String, seconds: i64) -> Result<bool> {
+        self.conn()
+            .expire(key, seconds)
+            .await
+            .map_err(|e| redis_error("EXPIRE", e))
+    }
+
+    /// TTL command - Get remaining time to live in seconds
+    ///
+    /// # Returns
+    /// TTL in seconds, -1 if no expiration, -2 if key doesn't exist
+    #[napi]
+    pub async fn ttl(&self, key: String) -> Result<i64> {
+        self.conn().ttl(key).await.map_err(|e| redis_error("TTL", e))
+    }
+
+    // ==================== Hash Commands ====================
+
+    /// HSET command - Set hash field
+    ///
+    /// # Returns
+    /// true if field is new, false if field was updated
+    #[napi]
+    pub async fn hset(&self, key: String, field: String, value: String) -> Result<bool> {
+        self.conn()
+            .hset(key, field, value)
+            .await
+            .map_err(|e| redis_error("HSET", e))
+    }
+
+    /// HGET command - Get hash field
+    ///
+    /// # Returns
+    /// The field value, or null if field doesn't exist
+    #[napi]
+    pub async fn hget(&self, key: String, field: String) -> Result<Option<String>> {
+        self.conn()
+            .hget(key, field)
+            .await
+            .map_err(|e| redis_error("HGET", e))
+    }
+
+    /// HGETALL command - Get all fields and values of a hash
+    ///
+    /// # Returns
+    /// Object with field names as keys
+    #[napi]
+    pub async fn hgetall(&self, key: String) -> Result<std::collections::HashMap<String, String>> {
+        self.conn()
+            .hgetall(key)
+            .await
+            .map_err(|e| redis_error("HGETALL", e))
+    }
+
+    /// HDEL command - Delete hash fields
+    ///
+    /// # Returns
+    /// Number of fields removed
+    #[napi]
+    pub async fn hdel(&self, key: String, fields: Vec<String>) -> Result<i64> {
+        self.conn()
+            .hdel(key, fields)
+            .await
+            .map_err(|e| redis_error("HDEL", e))
+    }
+
+    // ==================== List Commands ====================
+
+    /// LPUSH command - Prepend values to a list
+    ///
+    /// # Returns
+    /// Length of the list after the push
+    #[napi]
+    pub async fn lpush(&self, key: String, values: Vec<String>) -> Result<i64> {
+        self.conn()
+            .lpush(key, values)
+            .await
+            .map_err(|e| redis_error("LPUSH", e))
+    }
+
+    /// RPUSH command - Append values to a list
+    ///
+    /// # Returns
+    /// Length of the list after the push
+    #[napi]
+    pub async fn rpush(&self, key: String, values: Vec<String>) -> Result<i64> {
+        self.conn()
+            .rpush(key, values)
+            .await
+            .map_err(|e| redis_error("RPUSH", e))
+    }
+
+    /// LPOP command - Remove and return the first element
+    #[napi]
+    pub async fn lpop(&self, key: String) -> Result<Option<String>> {
+        self.conn()
+            .lpop(key, None)
+            .await
+            .map_err(|e| redis_error("LPOP", e))
+    }
+
+    /// RPOP command - Remove and return the last element
+    #[napi]
+    pub async fn rpop(&self, key: String) -> Result<Option<String>> {
+        self.conn()
+            .rpop(key, None)
+            .await
+            .map_err(|e| redis_error("RPOP", e))
+    }
+
+    /// LRANGE command - Get a range of elements
+    #[napi]
+    pub async fn lrange(&self, key: String, start: i32, stop: i32) -> Result<Vec<String>> {
+        self.conn()
+            .lrange(key, start as isize, stop as isize)
+            .await
+            .map_err(|e| redis_error("LRANGE", e))
+    }
+
+    /// LLEN command - Get the length of a list
+    #[napi]
+    pub async fn llen(&self, key: String) -> Result<i64> {
+        self.conn().llen(key).await.map_err(|e| redis_error("LLEN", e))
+    }
+
+    // ==================== Blocking Commands ====================
+
+    /// BLPOP command - Pop the first element of the first non-empty list, waiting if all are empty
+    ///
+    /// Runs on its own connection, so other requests on this connection are
+    /// not held up while it waits.
+    ///
+    /// # Arguments
+    /// * `keys` - Lists to pop from, checked in order
+    /// * `timeout_seconds` - Maximum time to wait (0 = wait forever)
+    /// * `token` - Optional CancellationToken that stops waiting
+    ///
+    /// # Returns
+    /// The list and popped element, or null if the timeout expired
+    ///
+    /// # Example (ArkTS)
+    /// ```typescript
+    /// const job = await conn.blpop(["jobs:high", "jobs:low"], 5);
+    /// if (job) {
+    ///   console.log(`${job.key}: ${job.value}`);
+    /// }
+    /// ```
+    #[napi(ts_return_type = "Promise<BlockingPopResult | null>")]
+    pub fn blpop<'env>(
+        &self,
+        env: &'env Env,
+        keys: Vec<String>,
+        timeout_seconds: f64,
+        token: Option<&CancellationToken>,
+    ) -> Result<PromiseRaw<'env, Option<BlockingPopResult>>> {
+        let client = self.client.clone();
+        let config = self.config.clone();
+        spawn_cancellable(env, token, "BLPOP", async move {
+            let popped: Option<(String, String)> = blocking_conn(client, config, timeout_seconds)
+                .await?
+                .blpop(keys, timeout_seconds)
+                .await
+                .map_err(|e| redis_error("BLPOP", e))?;
+            Ok(popped.map(|(key, value)| BlockingPopResult { key, value }))
+        })
+    }
+
+    /// BRPOP command - Pop the last element of the first non-empty list, waiting if all are empty
+    ///
+    /// # Arguments
+    /// * `keys` - Lists to pop from, checked in order
+    /// * `timeout_seconds` - Maximum time to wait (0 = wait forever)
+    /// * `token` - Optional CancellationToken that stops waiting
+    ///
+    /// # Returns
+    /// The list and popped element, or null if the timeout expired
+    #[napi(ts_return_type = "Promise<BlockingPopResult | null>")]
+    pub fn brpop<'env>(
+        &self,
+        env: &'env Env,
+        keys: Vec<String>,
+        timeout_seconds: f64,
+        token: Option<&CancellationToken>,
+    ) -> Result<PromiseRaw<'env, Option<BlockingPopResult>>> {
+        let client = self.client.clone();
+        let config = self.config.clone();
+        spawn_cancellable(env, token, "BRPOP", async move {
+            let popped: Option<(String, String)> = blocking_conn(client, config, timeout_seconds)
+                .await?
+                .brpop(keys, timeout_seconds)
+                .await
+                .map_err(|e| redis_error("BRPOP", e))?;
+            Ok(popped.map(|(key, value)| BlockingPopResult { key, value }))
+        })
+    }
+
+    /// BLMOVE command - Move an element between lists, waiting if the source is empty
+    ///
+    /// # Arguments
+    /// * `source` - List to pop from
+    /// * `destination` - List to push to
+    /// * `from` - End of `source` to pop from
+    /// * `to` - End of `destination` to push to
+    /// * `timeout_seconds` - Maximum time to wait (0 = wait forever)
+    /// * `token` - Optional CancellationToken that stops waiting
+    ///
+    /// # Returns
+    /// The moved element, or null if the timeout expired
+    ///
+    /// # Example (ArkTS)
+    /// ```typescript
+    /// import { ListDirection } from 'libredis_ohos.so';
+    ///
+    /// // Reliable queue: keep the job in "processing" until it is acknowledged
+    /// const job = await conn.blmove("jobs", "processing", ListDirection.Right, ListDirection.Left, 10);
+    /// ```
+    #[napi(ts_return_type = "Promise<string | null>")]
+    #[allow(clippy::too_many_arguments)]
+    pub fn blmove<'env>(
+        &self,
+        env: &'env Env,
+        source: String,
+        destination: String,
+        from: ListDirection,
+        to: ListDirection,
+        timeout_seconds: f64,
+        token: Option<&CancellationToken>,
+    ) -> Result<PromiseRaw<'env, Option<String>>> {
+        let client = self.client.clone();
+        let config = self.config.clone();
+        spawn_cancellable(env, token, "BLMOVE", async move {
+            blocking_conn(client, config, timeout_seconds)
+                .await?
+                .blmove(source, destination, from.into(), to.into(), timeout_seconds)
+                .await
+                .map_err(|e| redis_error("BLMOVE", e))
+        })
+    }
+
+    /// BZPOPMIN command - Pop the lowest scored member of the first non-empty sorted set, waiting if all are empty
+    ///
+    /// # Arguments
+    /// * `keys` - Sorted sets to pop from, checked in order
+    /// * `timeout_seconds` - Maximum time to wait (0 = wait forever)
+    /// * `token` - Optional CancellationToken that stops waiting
+    ///
+    /// # Returns
+    /// The sorted set, member and score, or null if the timeout expired
+    #[napi(ts_return_type = "Promise<BlockingZsetPopResult | null>")]
+    pub fn bzpopmin<'env>(
+        &self,
+        env: &'env Env,
+        keys: Vec<String>,
+        timeout_seconds: f64,
+        token: Option<&CancellationToken>,
+    ) -> Result<PromiseRaw<'env, Option<BlockingZsetPopResult>>> {
+        let client = self.client.clone();
+        let config = self.config.clone();
+        spawn_cancellable(env, token, "BZPOPMIN", async move {
+            let popped: Option<(String, String, f64)> = blocking_conn(client, config, timeout_seconds)
+                .await?
+                .bzpopmin(keys, timeout_seconds)
+                .await
+                .map_err(|e| redis_error("BZPOPMIN", e))?;
+            Ok(popped.map(|(key, member, score)| BlockingZsetPopResult { key, member, score }))
+        })
+    }
+
+    /// XREAD BLOCK command - Wait for new entries on one or more streams
+    ///
+    /// Runs on its own connection, so other requests on this connection are
+    /// not held up while it waits.
+    ///
+    /// # Arguments
+    /// * `keys` - Stream keys to read from
+    /// * `ids` - ID to read after, per stream (use "$" for entries added from now on)
+    /// * `block_ms` - Maximum time to wait in milliseconds (0 = wait forever)
+    /// * `count` - Optional maximum number of entries per stream
+    /// * `token` - Optional CancellationToken that stops waiting
+    ///
+    /// # Returns
+    /// Entries read from each stream, or null if the timeout expired
+    ///
+    /// # Example (ArkTS)
+    /// ```typescript
+    /// let lastId = "$";
+    /// while (!token.isCancelled) {
+    ///   const results = await conn.xreadBlocking(["chat:room1"], [lastId], 30000, 100, token);
+    ///   for (const entry of results?.[0].entries ?? []) {
+    ///     render(entry.fields);
+    ///     lastId = entry.id;
+    ///   }
+    /// }
+    /// ```
+    #[napi(ts_return_type = "Promise<Array<StreamReadResult> | null>")]
+    pub fn xread_blocking<'env>(
+        &self,
+        env: &'env Env,
+        keys: Vec<String>,
+        ids: Vec<String>,
+        block_ms: u32,
+        count: Option<i32>,
+        token: Option<&CancellationToken>,
+    ) -> Result<PromiseRaw<'env, Option<Vec<StreamReadResult>>>> {
+        let cmd = xread_cmd(None, &keys, &ids, count, Some(i64::from(block_ms)))?;
+        let client = self.client.clone();
+        let config = self.config.clone();
+        spawn_cancellable(env, token, "XREAD", async move {
+            let result: Option<StreamReadReply> = cmd
+                .query_async(&mut blocking_conn(client, config, block_ms as f64 / 1000.0).await?)
+                .await
+                .map_err(|e| redis_error("XREAD", e))?;
+            Ok(result.map(|streams| streams.into_iter().map(StreamReadResult::from).collect()))
+        })
+    }
+
+    // ==================== Set Commands ====================
+
+    /// SADD command - Add members to a set
+    ///
+    /// # Returns
+    /// Number of members added
+    #[napi]
+    pub async fn sadd(&self, key: String, members: Vec<String>) -> Result<i64> {
+        self.conn()
+            .sadd(key, members)
+            .await
+            .map_err(|e| redis_error("SADD", e))
+    }
+
+    /// SREM command - Remove members from a set
+    ///
+    /// # Returns
+    /// Number of members removed
+    #[napi]
+    pub async fn srem(&self, key: String, members: Vec<String>) -> Result<i64> {
+        self.conn()
+            .srem(key, members)
+            .await
+            .map_err(|e| redis_error("SREM", e))
+    }
+
+    /// SMEMBERS command - Get all members of a set
+    #[napi]
+    pub async fn smembers(&self, key: String) -> Result<Vec<String>> {
+        self.conn()
+            .smembers(key)
+            .await
+            .map_err(|e| redis_error("SMEMBERS", e))
+    }
+
+    /// SISMEMBER command - Check if a member is in a set
+    #[napi]
+    pub async fn sismember(&self, key: String, member: String) -> Result<bool> {
+        self.conn()
+            .sismember(key, member)
+            .await
+            .map_err(|e| redis_error("SISMEMBER", e))
+    }
+
+    // ==================== Pub/Sub (RESP3) ====================
+
+    /// SUBSCRIBE command - Subscribe to channels
+    ///
+    /// Messages arrive as "message" frames on the `onPush` callback, and the
+    /// connection stays usable for other commands. Only available on
+    /// connections created with `getPushConnection()`.
+    #[napi]
+    pub async fn subscribe(&self, channels: Vec<String>) -> Result<()> {
+        self.conn()
+            .subscribe(channels)
+            .await
+            .map_err(|e| redis_error("SUBSCRIBE", e))
+    }
+
+    /// PSUBSCRIBE command - Subscribe to channel patterns
+    ///
+    /// Messages arrive as "pmessage" frames on the `onPush` callback.
+    #[napi]
+    pub async fn psubscribe(&self, patterns: Vec<String>) -> Result<()> {
+        self.conn()
+            .psubscribe(patterns)
+            .await
+            .map_err(|e| redis_error("PSUBSCRIBE", e))
+    }
+
+    /// UNSUBSCRIBE command - Unsubscribe from channels
+    #[napi]
+    pub async fn unsubscribe(&self, channels: Vec<String>) -> Result<()> {
+        self.conn()
+            .unsubscribe(channels)
+            .await
+            .map_err(|e| redis_error("UNSUBSCRIBE", e))
+    }
+
+    // ==================== Utility Commands ====================
+
+    /// PING command - Test connection
+    ///
+    /// # Returns
+    /// "PONG"
+    #[napi]
+    pub async fn ping(&self) -> Result<String> {
+        redis::cmd("PING")
+            .query_async(&mut self.conn())
+            .await
+            .map_err(|e| redis_error("PING", e))
+    }
+
+    /// PUBLISH command - Post a message to a channel
+    ///
+    /// # Returns
+    /// Number of clients that received the message
+    #[napi]
+    pub async fn publish(&self, channel: String, message: String) -> Result<i64> {
+        self.conn()
+            .publish(channel, message)
+            .await
+            .map_err(|e| redis_error("PUBLISH", e))
+    }
+
+    // ==================== Pipelining ====================
+
+    /// Start a pipeline on this connection
+    ///
+    /// Queued commands are sent in a single round trip by `exec()`, which
+    /// resolves with their results in order.
+    ///
+    /// # Example (ArkTS)
+    /// ```typescript
+    /// const pipe = conn.pipelineAsync();
+    /// for (const id of visibleIds) {
+    ///   pipe.hgetall(`item:${id}`);
+    /// }
+    /// const items = (await pipe.exec()).map((json) => JSON.parse(json));
+    /// ```
+    #[napi]
+    pub fn pipeline_async(&self) -> AsyncRedisPipeline {
+        AsyncRedisPipeline::new(self.conn())
+    }
+
+    // ==================== Timeouts ====================
+
+    /// Set how long commands on this connection wait for a reply
+    ///
+    /// A command without a reply in time rejects with a `RedisErrorKind.Timeout`
+    /// error. Blocking commands are not affected; they wait for their own
+    /// timeout argument.
+    ///
+    /// # Arguments
+    /// * `timeout_ms` - Reply timeout in milliseconds (default: 500)
+    ///
+    /// # Example (ArkTS)
+    /// ```typescript
+    /// conn.setCommandTimeout(2000);
+    /// try {
+    ///   await conn.get("profile:42");
+    /// } catch (e) {
+    ///   if (parseRedisError(e.message)?.kind === RedisErrorKind.Timeout) {
+    ///     showOfflineBanner();
+    ///   }
+    /// }
+    /// ```
+    #[napi]
+    pub fn set_command_timeout(&mut self, timeout_ms: u32) -> Result<()> {
+        self.inner.set_response_timeout(command_timeout(timeout_ms)?);
+        Ok(())
+    }
+
+    // ==================== Generic Command Interface ====================
+
+    /// Execute a raw Redis command
+    ///
+    /// # Arguments
+    /// * `command` - The Redis command name (e.g., "GET", "SET", "HGETALL")
+    /// * `args` - Array of command arguments
+    /// * `token` - Optional CancellationToken that aborts the request
+    /// * `timeout_ms` - Reply timeout for this call, overriding the connection's command timeout
+    ///
+    /// # Returns
+    /// JSON string representation of the Redis response
+    ///
+    /// # Example (ArkTS)
+    /// ```typescript
+    /// const json = await conn.cmd("HGETALL", ["user:42"]);
+    /// const user = JSON.parse(json);
+    ///
+    /// // Slow server-side command: allow up to 10 seconds
+    /// const info = await conn.cmd("MEMORY", ["DOCTOR"], undefined, 10000);
+    /// ```
+    #[napi(ts_return_type = "Promise<string>")]
+    pub fn cmd<'env>(
+        &self,
+        env: &'env Env,
+        command: String,
+        args: Vec<String>,
+        token: Option<&CancellationToken>,
+        timeout_ms: Option<u32>,
+    ) -> Result<PromiseRaw<'env, String>> {
+        let mut conn = self.conn();
+        if let Some(timeout_ms) = timeout_ms {
+            conn.set_response_timeout(command_timeout(timeout_ms)?);
+        }
+        spawn_cancellable(env, token, "CMD", async move {
+            let result: redis::Value = redis::cmd(&command)
+                .arg(args)
+                .query_async(&mut conn)
+                .await
+                .map_err(|e| redis_error(&format!("CMD {}", command), e))?;
+
+            let json = redis_value_to_json_value(&result)?;
+            serde_json::to_string(&json)
+                .map_err(|e| napi_ohos::Error::from_reason(format!("JSON serialization failed: {}", e)))
+        })
+    }
+
+    /// Execute a command given as a single argument array
+    ///
+    /// The first element is the command name, possibly with a subcommand.
+    ///
+    /// # Arguments
+    /// * `args` - Command name followed by its arguments
+    /// * `token` - Optional CancellationToken that aborts the request
+    ///
+    /// # Returns
+    /// The reply, tagged with its RESP kind
+    ///
+    /// # Example (ArkTS)
+    /// ```typescript
+    /// const reply = await conn.execute(["BF.ADD", "signups", "alice@example.com"]);
+    /// const added = reply.int === 1;
+    /// ```
+    #[napi(ts_return_type = "Promise<RedisValue>")]
+    pub fn execute<'env>(
+        &self,
+        env: &'env Env,
+        args: Vec<String>,
+        token: Option<&CancellationToken>,
+    ) -> Result<PromiseRaw<'env, RedisValue>> {
+        let cmd = command_from_args(&args)?;
+        let mut conn = self.conn();
+        spawn_cancellable(env, token, "CMD", async move {
+            let result: redis::Value = cmd
+                .query_async(&mut conn)
+                .await
+                .map_err(|e| redis_error(&format!("CMD {}", args[0]), e))?;
+            Ok(RedisValue::from_redis_value(&result, false))
+        })
+    }
+
+    /// Execute a command whose arguments may contain binary data
+    ///
+    /// Same as `execute()`, but every argument is sent byte-for-byte, and
+    /// bulk strings in the reply are returned in `bytes`.
+    ///
+    /// # Arguments
+    /// * `args` - Command name followed by its arguments
+    /// * `token` - Optional CancellationToken that aborts the request
+    #[napi(ts_return_type = "Promise<RedisValue>")]
+    pub fn execute_buffer<'env>(
+        &self,
+        env: &'env Env,
+        args: Vec<Buffer>,
+        token: Option<&CancellationToken>,
+    ) -> Result<PromiseRaw<'env, RedisValue>> {
+        let cmd = command_from_args(&args)?;
+        let name = String::from_utf8_lossy(&args[0]).into_owned();
+        let mut conn = self.conn();
+        spawn_cancellable(env, token, "CMD", async move {
+            let result: redis::Value = cmd
+                .query_async(&mut conn)
+                .await
+                .map_err(|e| redis_error(&format!("CMD {}", name), e))?;
+            Ok(RedisValue::from_redis_value(&result, true))
+        })
+    }
+}
@@ -0,0 +1,244 @@
+// Async Redis JSON Commands wrapper for HarmonyOS NAPI
+//
+// Promise-returning variant of RedisJsonConnection. Reading and writing large
+// documents happens on the tokio runtime instead of the ArkTS thread.
+
+use napi_derive_ohos::napi;
+use napi_ohos::{Error, Result, Status};
+use redis::aio::MultiplexedConnection;
+use redis::JsonAsyncCommands;
+use serde_json::Value as JsonValue;
+
+use crate::error::redis_error;
+
+/// Async Redis JSON Connection for HarmonyOS
+///
+/// Same commands as RedisJsonConnection, but every method returns a Promise.
+/// Created with `RedisClient.getJsonConnectionAsync()`.
+///
+/// # Example (ArkTS)
+/// ```typescript
+/// const jsonConn = await client.getJsonConnectionAsync();
+/// await jsonConn.jsonSet("user:1", "$", JSON.stringify({name: "John", age: 30}));
+/// const user = JSON.parse(await jsonConn.jsonGet("user:1", "$"));
+/// ```
+#[napi]
+pub struct AsyncRedisJsonConnection {
+    inner: MultiplexedConnection,
+}
+
+impl AsyncRedisJsonConnection {
+    pub(crate) fn new(conn: MultiplexedConnection) -> Self {
+        AsyncRedisJsonConnection { inner: conn }
+    }
+
+    /// Clone of the connection handle for one request
+    fn conn(&self) -> MultiplexedConnection {
+        self.inner.clone()
+    }
+}
+
+fn parse_json(json: &str) -> Result<JsonValue> {
+    serde_json::from_str(json).map_err(|e| Error::new(Status::InvalidArg, format!("Invalid JSON: {}", e)))
+}
+
+#[napi]
+impl AsyncRedisJsonConnection {
+    // ==================== Core JSON Commands ====================
+
+    /// JSON.SET command - Set JSON value at path
+    ///
+    /// # Arguments
+    /// * `key` - The key to set
+    /// * `path` - JSONPath expression (use "$" for root)
+    /// * `json` - JSON string value to set
+    #[napi]
+    pub async fn json_set(&self, key: String, path: String, json: String) -> Result<bool> {
+        let value = parse_json(&json)?;
+        self.conn()
+            .json_set(key, path, &value)
+            .await
+            .map_err(|e| redis_error("JSON.SET", e))
+    }
+
+    /// JSON.GET command - Get JSON value at path
+    ///
+    /// # Arguments
+    /// * `key` - The key to get
+    /// * `path` - JSONPath expression (use "$" for root)
+    ///
+    /// # Returns
+    /// JSON string (wrapped in array for JSONPath results)
+    #[napi]
+    pub async fn json_get(&self, key: String, path: String) -> Result<String> {
+        self.conn()
+            .json_get(key, path)
+            .await
+            .map_err(|e| redis_error("JSON.GET", e))
+    }
+
+    /// JSON.DEL command - Delete JSON value at path
+    ///
+    /// # Returns
+    /// Number of paths deleted
+    #[napi]
+    pub async fn json_del(&self, key: String, path: String) -> Result<i64> {
+        self.conn()
+            .json_del(key, path)
+            .await
+            .map_err(|e| redis_error("JSON.DEL", e))
+    }
+
+    /// JSON.TYPE command - Get the type of JSON value at path
+    ///
+    /// # Returns
+    /// Type name: "object", "array", "string", "number", "boolean", "null"
+    #[napi]
+    pub async fn json_type(&self, key: String, path: String) -> Result<String> {
+        self.conn()
+            .json_type(key, path)
+            .await
+            .map_err(|e| redis_error("JSON.TYPE", e))
+    }
+
+    // ==================== JSON Array Commands ====================
+
+    /// JSON.ARRAPPEND command - Append values to JSON array
+    ///
+    /// # Returns
+    /// New length of the array
+    #[napi]
+    pub async fn json_arr_append(&self, key: String, path: String, json: String) -> Result<i64> {
+        let value = parse_json(&json)?;
+        self.conn()
+            .json_arr_append(key, path, &value)
+            .await
+            .map_err(|e| redis_error("JSON.ARRAPPEND", e))
+    }
+
+    /// JSON.ARRINDEX command - Find index of JSON value in array
+    ///
+    /// # Returns
+    /// Index of first occurrence, or -1 if not found
+    #[napi]
+    pub async fn json_arr_index(&self, key: String, path: String, json: String) -> Result<i64> {
+        let value = parse_json(&json)?;
+        self.conn()
+            .json_arr_index(key, path, &value)
+            .await
+            .map_err(|e| redis_error("JSON.ARRINDEX", e))
+    }
+
+    /// JSON.ARRINSERT command - Insert values into JSON array
+    ///
+    /// # Returns
+    /// New length of the array
+    #[napi]
+    pub async fn json_arr_insert(&self, key: String, path: String, index: i32, json: String) -> Result<i64> {
+        let value = parse_json(&json)?;
+        self.conn()
+            .json_arr_insert(key, path, index as i64, &value)
+            .await
+            .map_err(|e| redis_error("JSON.ARRINSERT", e))
+    }
+
+    /// JSON.ARRLEN command - Get length of JSON array
+    #[napi]
+    pub async fn json_arr_len(&self, key: String, path: String) -> Result<i64> {
+        self.conn()
+            .json_arr_len(key, path)
+            .await
+            .map_err(|e| redis_error("JSON.ARRLEN", e))
+    }
+
+    /// JSON.ARRPOP command - Remove and return element from array
+    ///
+    /// # Arguments
+    /// * `index` - Index to pop (default: -1 for last element)
+    ///
+    /// # Returns
+    /// The popped JSON value as string
+    #[napi]
+    pub async fn json_arr_pop(&self, key: String, path: String, index: Option<i32>) -> Result<String> {
+        let idx = index.unwrap_or(-1) as i64;
+        self.conn()
+            .json_arr_pop(key, path, idx)
+            .await
+            .map_err(|e| redis_error("JSON.ARRPOP", e))
+    }
+
+    /// JSON.ARRTRIM command - Trim array to specified range
+    ///
+    /// # Returns
+    /// New length of the array
+    #[napi]
+    pub async fn json_arr_trim(&self, key: String, path: String, start: i32, stop: i32) -> Result<i64> {
+        self.conn()
+            .json_arr_trim(key, path, start as i64, stop as i64)
+            .await
+            .map_err(|e| redis_error("JSON.ARRTRIM", e))
+    }
+
+    // ==================== JSON Object Commands ====================
+
+    /// JSON.OBJKEYS command - Get keys of JSON object
+    #[napi]
+    pub async fn json_obj_keys(&self, key: String, path: String) -> Result<Vec<String>> {
+        self.conn()
+            .json_obj_keys(key, path)
+            .await
+            .map_err(|e| redis_error("JSON.OBJKEYS", e))
+    }
+
+    /// JSON.OBJLEN command - Get number of keys in JSON object
+    #[napi]
+    pub async fn json_obj_len(&self, key: String, path: String) -> Result<i64> {
+        self.conn()
+            .json_obj_len(key, path)
+            .await
+            .map_err(|e| redis_error("JSON.OBJLEN", e))
+    }
+
+    // ==================== JSON String Commands ====================
+
+    /// JSON.STRAPPEND command - Append string to JSON string value
+    ///
+    /// # Arguments
+    /// * `value` - String to append (will be JSON-encoded)
+    ///
+    /// # Returns
+    /// New length of the string
+    #[napi]
+    pub async fn json_str_append(&self, key: String, path: String, value: String) -> Result<i64> {
+        let json_str = serde_json::to_string(&value)
+            .map_err(|e| Error::new(Status::InvalidArg, format!("Failed to encode string: {}", e)))?;
+
+        self.conn()
+            .json_str_append(key, path, &json_str)
+            .await
+            .map_err(|e| redis_error("JSON.STRAPPEND", e))
+    }
+
+    /// JSON.STRLEN command - Get length of JSON string value
+    #[napi]
+    pub async fn json_str_len(&self, key: String, path: String) -> Result<i64> {
+        self.conn()
+            .json_str_len(key, path)
+            .await
+            .map_err(|e| redis_error("JSON.STRLEN", e))
+    }
+
+    // ==================== JSON Number Commands ====================
+
+    /// JSON.NUMINCRBY command - Increment number value
+    ///
+    /// # Returns
+    /// New value as JSON string
+    #[napi]
+    pub async fn json_num_incr_by(&self, key: String, path: String, value: i64) -> Result<String> {
+        self.conn()
+            .json_num_incr_by(key, path, value)
+            .await
+            .map_err(|e| redis_error("JSON.NUMINCRBY", e))
+    }
+}
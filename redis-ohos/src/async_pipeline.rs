@@ -0,0 +1,217 @@
+// Async pipeline for HarmonyOS NAPI
+//
+// Commands are queued on the ArkTS thread and sent in a single round trip by
+// `exec()`, which resolves with one result per command.
+
+use napi_derive_ohos::napi;
+use napi_ohos::bindgen_prelude::*;
+use redis::aio::{ConnectionLike, MultiplexedConnection};
+use redis::{Pipeline, Value};
+
+use crate::cancel::{spawn_cancellable, CancellationToken};
+use crate::error::redis_error;
+use crate::pipeline::{chunk_size, chunks, DEFAULT_PIPELINE_CHUNK};
+use crate::types::RedisValue;
+
+/// Batch of commands sent in one round trip
+///
+/// Created with `AsyncRedisConnection.pipelineAsync()`. Command methods
+/// return the pipeline, so calls can be chained.
+///
+/// Large batches are sent in chunks of `chunkSize()` commands (1000 by
+/// default); results are still returned as one array.
+///
+/// # Example (ArkTS)
+/// ```typescript
+/// const results = await conn.pipelineAsync()
+///   .get("user:1:name")
+///   .hgetall("user:1:profile")
+///   .incr("page:views")
+///   .exec();
+///
+/// const name = results[0].string;
+/// ```
+#[napi]
+pub struct AsyncRedisPipeline {
+    conn: MultiplexedConnection,
+    pipe: Pipeline,
+    chunk_size: usize,
+}
+
+impl AsyncRedisPipeline {
+    pub(crate) fn new(conn: MultiplexedConnection) -> Self {
+        AsyncRedisPipeline {
+            conn,
+            pipe: redis::pipe(),
+            chunk_size: DEFAULT_PIPELINE_CHUNK,
+        }
+    }
+
+    /// Take the queued commands, leaving an empty pipeline in the same mode
+    fn take_pipe(&mut self) -> Pipeline {
+        let mut fresh = redis::pipe();
+        if self.pipe.is_transaction() {
+            fresh.atomic();
+        }
+        std::mem::replace(&mut self.pipe, fresh)
+    }
+}
+
+#[napi]
+impl AsyncRedisPipeline {
+    /// Queue any Redis command
+    ///
+    /// # Arguments
+    /// * `command` - The Redis command name (e.g., "ZADD")
+    /// * `args` - Array of command arguments
+    #[napi]
+    pub fn cmd<'env>(&mut self, this: This<'env>, command: String, args: Vec<String>) -> This<'env> {
+        self.pipe.cmd(&command).arg(args);
+        this
+    }
+
+    /// Queue a SET command
+    #[napi]
+    pub fn set<'env>(&mut self, this: This<'env>, key: String, value: String) -> This<'env> {
+        self.pipe.set(key, value);
+        this
+    }
+
+    /// Queue a GET command
+    #[napi]
+    pub fn get<'env>(&mut self, this: This<'env>, key: String) -> This<'env> {
+        self.pipe.get(key);
+        this
+    }
+
+    /// Queue a DEL command
+    #[napi]
+    pub fn del<'env>(&mut self, this: This<'env>, keys: Vec<String>) -> This<'env> {
+        self.pipe.del(keys);
+        this
+    }
+
+    /// Queue an INCRBY command
+    ///
+    /// # Arguments
+    /// * `delta` - Amount to add (default: 1)
+    #[napi]
+    pub fn incr<'env>(&mut self, this: This<'env>, key: String, delta: Option<i64>) -> This<'env> {
+        self.pipe.incr(key, delta.unwrap_or(1));
+        this
+    }
+
+    /// Queue an EXPIRE command
+    #[napi]
+    pub fn expire<'env>(&mut self, this: This<'env>, key: String, seconds: i64) -> This<'env> {
+        self.pipe.expire(key, seconds);
+        this
+    }
+
+    /// Queue an HSET command
+    #[napi]
+    pub fn hset<'env>(&mut self, this: This<'env>, key: String, field: String, value: String) -> This<'env> {
+        self.pipe.hset(key, field, value);
+        this
+    }
+
+    /// Queue an HGET command
+    #[napi]
+    pub fn hget<'env>(&mut self, this: This<'env>, key: String, field: String) -> This<'env> {
+        self.pipe.hget(key, field);
+        this
+    }
+
+    /// Queue an HGETALL command
+    #[napi]
+    pub fn hgetall<'env>(&mut self, this: This<'env>, key: String) -> This<'env> {
+        self.pipe.hgetall(key);
+        this
+    }
+
+    /// Run the queued commands in a MULTI/EXEC transaction
+    ///
+    /// The transaction is still sent in a single round trip. Applies to
+    /// every batch executed afterwards.
+    #[napi]
+    pub fn atomic<'env>(&mut self, this: This<'env>) -> This<'env> {
+        self.pipe.atomic();
+        this
+    }
+
+    /// Set the maximum number of commands sent per round trip
+    ///
+    /// Transactions are always sent in one round trip, whatever their size.
+    ///
+    /// # Arguments
+    /// * `size` - Commands per chunk (default: 1000)
+    #[napi]
+    pub fn chunk_size<'env>(&mut self, this: This<'env>, size: u32) -> Result<This<'env>> {
+        self.chunk_size = chunk_size(size)?;
+        Ok(this)
+    }
+
+    /// Number of queued commands
+    #[napi(getter)]
+    pub fn length(&self) -> u32 {
+        self.pipe.len() as u32
+    }
+
+    /// Send all queued commands in one round trip
+    ///
+    /// The pipeline is emptied and can be reused for the next batch. A
+    /// command rejected by the server does not fail the others; its result
+    /// is an Error reply.
+    ///
+    /// # Arguments
+    /// * `token` - Optional CancellationToken that aborts the request
+    ///
+    /// # Returns
+    /// Each reply, in queue order
+    #[napi(ts_return_type = "Promise<Array<RedisValue>>")]
+    pub fn exec<'env>(
+        &mut self,
+        env: &'env Env,
+        token: Option<&CancellationToken>,
+    ) -> Result<PromiseRaw<'env, Vec<RedisValue>>> {
+        let pipe = self.take_pipe();
+        let mut conn = self.conn.clone();
+        let size = self.chunk_size;
+
+        spawn_cancellable(env, token, "Pipeline", async move {
+            if pipe.is_empty() {
+                return Ok(Vec::new());
+            }
+
+            let replies = if pipe.is_transaction() {
+                // Skip the replies to MULTI and to each QUEUED command
+                let mut exec = conn
+                    .req_packed_commands(&pipe, pipe.len() + 1, 1)
+                    .await
+                    .map_err(|e| redis_error("EXEC", e))?;
+                match exec.pop() {
+                    Some(Value::Array(replies)) => replies,
+                    Some(Value::Nil) | None => {
+                        return Err(napi_ohos::Error::from_reason("EXEC failed: transaction aborted"))
+                    }
+                    Some(other) => vec![other],
+                }
+            } else {
+                let mut replies = Vec::with_capacity(pipe.len());
+                for chunk in chunks(&pipe, size) {
+                    replies.extend(
+                        conn.req_packed_commands(&chunk, 0, chunk.len())
+                            .await
+                            .map_err(|e| redis_error("Pipeline", e))?,
+                    );
+                }
+                replies
+            };
+
+            Ok(replies
+                .iter()
+                .map(|reply| RedisValue::from_redis_value(reply, false))
+                .collect())
+        })
+    }
+}
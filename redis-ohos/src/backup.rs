@@ -0,0 +1,287 @@
+// Key backup file formats for HarmonyOS NAPI
+//
+// Two on-disk formats are supported:
+// - Dump: compact binary records (magic header followed by length-prefixed fields)
+// - Json: one JSON object per line with the DUMP payload base64-encoded
+
+use base64::Engine;
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Read, Seek, SeekFrom, Write};
+
+use crate::types::KeyExportFormat;
+
+/// Magic header at the start of binary dump files
+const DUMP_MAGIC: &[u8; 8] = b"ROHSDUMP";
+
+/// Version of the binary dump format
+const DUMP_VERSION: u8 = 1;
+
+/// A single exported key
+#[derive(Debug, Clone)]
+pub(crate) struct DumpRecord {
+    /// The key name
+    pub key: String,
+    /// Remaining time to live in milliseconds, or -1 for no expiration
+    pub pttl: i64,
+    /// The serialized value as returned by DUMP
+    pub payload: Vec<u8>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+struct JsonRecord {
+    key: String,
+    pttl: i64,
+    payload: String,
+}
+
+fn io_error(path: &str, e: impl std::fmt::Display) -> napi_ohos::Error {
+    napi_ohos::Error::from_reason(format!("Backup file '{}' error: {}", path, e))
+}
+
+/// Writes DumpRecords to a file in the requested format
+pub(crate) struct DumpWriter {
+    path: String,
+    format: KeyExportFormat,
+    out: BufWriter<File>,
+}
+
+impl DumpWriter {
+    pub fn create(path: &str, format: KeyExportFormat) -> napi_ohos::Result<Self> {
+        let file = File::create(path).map_err(|e| io_error(path, e))?;
+        let mut out = BufWriter::new(file);
+
+        if format == KeyExportFormat::Dump {
+            out.write_all(DUMP_MAGIC)
+                .and_then(|_| out.write_all(&[DUMP_VERSION]))
+                .map_err(|e| io_error(path, e))?;
+        }
+
+        Ok(DumpWriter {
+            path: path.to_string(),
+            format,
+            out,
+        })
+    }
+
+    pub fn write(&mut self, record: &DumpRecord) -> napi_ohos::Result<()> {
+        let result = match self.format {
+            KeyExportFormat::Dump => {
+                let key = record.key.as_bytes();
+                self.out
+                    .write_all(&(key.len() as u32).to_be_bytes())
+                    .and_then(|_| self.out.write_all(key))
+                    .and_then(|_| self.out.write_all(&record.pttl.to_be_bytes()))
+                    .and_then(|_| self.out.write_all(&(record.payload.len() as u32).to_be_bytes()))
+                    .and_then(|_| self.out.write_all(&record.payload))
+            }
+            KeyExportFormat::Json => {
+                let line = JsonRecord {
+                    key: record.key.clone(),
+                    pttl: record.pttl,
+                    payload: base64::engine::general_purpose::STANDARD.encode(&record.payload),
+                };
+                serde_json::to_writer(&mut self.out, &line)
+                    .map_err(std::io::Error::from)
+                    .and_then(|_| self.out.write_all(b"\n"))
+            }
+        };
+
+        result.map_err(|e| io_error(&self.path, e))
+    }
+
+    pub fn finish(mut self) -> napi_ohos::Result<()> {
+        self.out.flush().map_err(|e| io_error(&self.path, e))
+    }
+}
+
+/// Reads DumpRecords from a file, detecting the format from its header
+pub(crate) struct DumpReader {
+    path: String,
+    format: KeyExportFormat,
+    input: BufReader<File>,
+}
+
+impl DumpReader {
+    pub fn open(path: &str) -> napi_ohos::Result<Self> {
+        let mut file = File::open(path).map_err(|e| io_error(path, e))?;
+
+        let mut header = [0u8; 9];
+        let is_dump = match file.read_exact(&mut header) {
+            Ok(()) => &header[..8] == DUMP_MAGIC,
+            Err(_) => false,
+        };
+
+        let format = if is_dump {
+            if header[8] != DUMP_VERSION {
+                return Err(io_error(path, format!("unsupported dump version {}", header[8])));
+            }
+            KeyExportFormat::Dump
+        } else {
+            file.seek(SeekFrom::Start(0)).map_err(|e| io_error(path, e))?;
+            KeyExportFormat::Json
+        };
+
+        Ok(DumpReader {
+            path: path.to_string(),
+            format,
+            input: BufReader::new(file),
+        })
+    }
+
+    /// Read the next record, or None at the end of the file
+    pub fn next_record(&mut self) -> napi_ohos::Result<Option<DumpRecord>> {
+        match self.format {
+            KeyExportFormat::Dump => self.next_binary(),
+            KeyExportFormat::Json => self.next_json(),
+        }
+    }
+
+    fn next_binary(&mut self) -> napi_ohos::Result<Option<DumpRecord>> {
+        let mut len = [0u8; 4];
+        match self.input.read_exact(&mut len) {
+            Ok(()) => {}
+            Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => return Ok(None),
+            Err(e) => return Err(io_error(&self.path, e)),
+        }
+
+        let mut key = vec![0u8; u32::from_be_bytes(len) as usize];
+        let mut pttl = [0u8; 8];
+        self.input
+            .read_exact(&mut key)
+            .and_then(|_| self.input.read_exact(&mut pttl))
+            .and_then(|_| self.input.read_exact(&mut len))
+            .map_err(|e| io_error(&self.path, e))?;
+
+        let mut payload = vec![0u8; u32::from_be_bytes(len) as usize];
+        self.input
+            .read_exact(&mut payload)
+            .map_err(|e| io_error(&self.path, e))?;
+
+        Ok(Some(DumpRecord {
+            key: String::from_utf8(key).map_err(|e| io_error(&self.path, e))?,
+            pttl: i64::from_be_bytes(pttl),
+            payload,
+        }))
+    }
+
+    fn next_json(&mut self) -> napi_ohos::Result<Option<DumpRecord>> {
+        let mut line = String::new();
+        loop {
+            line.clear();
+            let read = self
+                .input
+                .read_line(&mut line)
+                .map_err(|e| io_error(&self.path, e))?;
+            if read == 0 {
+                return Ok(None);
+            }
+            if !line.trim().is_empty() {
+                break;
+            }
+        }
+
+        let record: JsonRecord =
+            serde_json::from_str(line.trim()).map_err(|e| io_error(&self.path, e))?;
+        let payload = base64::engine::general_purpose::STANDARD
+            .decode(record.payload)
+            .map_err(|e| io_error(&self.path, e))?;
+
+        Ok(Some(DumpRecord {
+            key: record.key,
+            pttl: record.pttl,
+            payload,
+        }))
+    }
+}
+
+/// Splits a redis-cli `--pipe` protocol file into individual commands
+///
+/// Commands are RESP arrays of bulk strings; inline commands (one command per
+/// text line) are passed through as well, since the server accepts both.
+pub(crate) struct RespCommandReader {
+    path: String,
+    input: BufReader<File>,
+    line: Vec<u8>,
+}
+
+impl RespCommandReader {
+    pub fn open(path: &str) -> napi_ohos::Result<Self> {
+        let file = File::open(path).map_err(|e| io_error(path, e))?;
+        Ok(RespCommandReader {
+            path: path.to_string(),
+            input: BufReader::new(file),
+            line: Vec::new(),
+        })
+    }
+
+    /// Append the raw bytes of the next command to `out`
+    ///
+    /// Returns false at the end of the file.
+    pub fn next_command(&mut self, out: &mut Vec<u8>) -> napi_ohos::Result<bool> {
+        loop {
+            if !self.read_line()? {
+                return Ok(false);
+            }
+            if self.line.iter().all(|b| b.is_ascii_whitespace()) {
+                continue;
+            }
+            break;
+        }
+
+        if self.line[0] != b'*' {
+            // Inline command
+            out.extend_from_slice(&self.line);
+            if !self.line.ends_with(b"\n") {
+                out.extend_from_slice(b"\r\n");
+            }
+            return Ok(true);
+        }
+
+        let args = self.parse_length(b'*')?;
+        out.extend_from_slice(&self.line);
+
+        for _ in 0..args {
+            if !self.read_line()? {
+                return Err(io_error(&self.path, "unexpected end of file inside a command"));
+            }
+            let len = self.parse_length(b'$')?;
+            out.extend_from_slice(&self.line);
+
+            let start = out.len();
+            out.resize(start + len + 2, 0);
+            self.input
+                .read_exact(&mut out[start..])
+                .map_err(|e| io_error(&self.path, e))?;
+        }
+
+        Ok(true)
+    }
+
+    // Read one line including its terminator into `self.line`
+    fn read_line(&mut self) -> napi_ohos::Result<bool> {
+        self.line.clear();
+        let read = self
+            .input
+            .read_until(b'\n', &mut self.line)
+            .map_err(|e| io_error(&self.path, e))?;
+        Ok(read > 0)
+    }
+
+    // Parse a `*<n>` or `$<n>` header line
+    fn parse_length(&self, prefix: u8) -> napi_ohos::Result<usize> {
+        let invalid = || {
+            io_error(
+                &self.path,
+                format!("invalid RESP header '{}'", String::from_utf8_lossy(&self.line).trim_end()),
+            )
+        };
+
+        if self.line.first() != Some(&prefix) {
+            return Err(invalid());
+        }
+        std::str::from_utf8(&self.line[1..])
+            .ok()
+            .and_then(|s| s.trim_end().parse().ok())
+            .ok_or_else(invalid)
+    }
+}
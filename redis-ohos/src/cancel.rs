@@ -0,0 +1,113 @@
+// Cancellation of Promise-returning commands for HarmonyOS NAPI
+//
+// A CancellationToken is passed to long-running async commands. Cancelling it
+// rejects their Promises and drops the pending request; for blocking commands
+// this closes their dedicated connection, which releases the server side too.
+
+use napi_derive_ohos::napi;
+use napi_ohos::bindgen_prelude::*;
+use napi_ohos::tokio::sync::Notify;
+use std::future::Future;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::task::Poll;
+
+use crate::error::cancelled_error;
+
+#[derive(Default)]
+struct TokenState {
+    cancelled: AtomicBool,
+    notify: Notify,
+}
+
+/// Token for cancelling in-flight async commands
+///
+/// One token can be shared by every command started for a page, and
+/// cancelled when the page is destroyed. Cancelled commands reject with a
+/// `RedisErrorKind.Cancelled` error.
+///
+/// # Example (ArkTS)
+/// ```typescript
+/// import { CancellationToken } from 'libredis_ohos.so';
+///
+/// const token = new CancellationToken();
+/// conn.blpop(["jobs"], 0, token)
+///   .then((job) => handle(job))
+///   .catch((e) => {
+///     if (parseRedisError(e.message)?.kind !== RedisErrorKind.Cancelled) throw e;
+///   });
+///
+/// // aboutToDisappear()
+/// token.cancel();
+/// ```
+#[napi]
+pub struct CancellationToken {
+    state: Arc<TokenState>,
+}
+
+#[napi]
+impl CancellationToken {
+    /// Create a token that is not cancelled
+    #[napi(constructor)]
+    pub fn new() -> Self {
+        CancellationToken {
+            state: Arc::new(TokenState::default()),
+        }
+    }
+
+    /// Cancel every command started with this token
+    ///
+    /// Commands started with it afterwards are rejected immediately.
+    #[napi]
+    pub fn cancel(&self) {
+        self.state.cancelled.store(true, Ordering::Release);
+        self.state.notify.notify_waiters();
+    }
+
+    /// Whether `cancel()` has been called
+    #[napi(getter)]
+    pub fn is_cancelled(&self) -> bool {
+        self.state.cancelled.load(Ordering::Acquire)
+    }
+}
+
+impl Default for CancellationToken {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Run a command on the async runtime, rejecting it when `token` is cancelled
+pub(crate) fn spawn_cancellable<'env, T, F>(
+    env: &'env Env,
+    token: Option<&CancellationToken>,
+    context: &'static str,
+    fut: F,
+) -> Result<PromiseRaw<'env, T>>
+where
+    T: 'static + Send + ToNapiValue,
+    F: 'static + Send + Future<Output = Result<T>>,
+{
+    let Some(state) = token.map(|token| token.state.clone()) else {
+        return env.spawn_future(fut);
+    };
+
+    env.spawn_future(async move {
+        let notified = state.notify.notified();
+        let mut notified = std::pin::pin!(notified);
+        // Register before checking the flag so a concurrent cancel() is not missed
+        notified.as_mut().enable();
+        if state.cancelled.load(Ordering::Acquire) {
+            return Err(cancelled_error(context));
+        }
+
+        let mut fut = std::pin::pin!(fut);
+        std::future::poll_fn(|cx| {
+            if let Poll::Ready(result) = fut.as_mut().poll(cx) {
+                return Poll::Ready(result);
+            }
+            notified.as_mut().poll(cx).map(|_| Err(cancelled_error(context)))
+        })
+        .await
+    })
+}
@@ -2,18 +2,70 @@
 
 use napi_derive_ohos::napi;
 use napi_ohos::bindgen_prelude::*;
-use redis::Client;
+use napi_ohos::threadsafe_function::{ThreadsafeFunction, ThreadsafeFunctionCallMode};
+use redis::io::tcp::socket2::TcpKeepalive;
+use redis::io::tcp::TcpSettings;
+use redis::{
+    AsyncConnectionConfig, Client, ClientTlsConfig, ConnectionAddr, ConnectionInfo, IntoConnectionInfo, ProtocolVersion,
+    PushInfo, TlsCertificates,
+};
+use std::collections::HashMap;
+use std::sync::Arc;
 
-use crate::connection::RedisConnection;
+use crate::async_connection::{command_timeout, push_message, AsyncRedisConnection};
+use crate::async_json_connection::AsyncRedisJsonConnection;
+use crate::cluster::key_slot;
+use crate::connection::{socket_timeout, RedisConnection};
+use crate::delayed_queue::RedisDelayedQueue;
+use crate::dns::AddressPreference;
+use crate::error::{redis_error, report_background_error};
 use crate::json_connection::RedisJsonConnection;
-use crate::types::RedisClientConfig;
+use crate::keyspace::KeyEventWatcher;
+use crate::metrics::{ConnectionRetry, Reconnect, RedirectPolicy, TransientRetry};
+use crate::presence::PresenceTracker;
+use crate::rpc::RedisRpcServer;
+use crate::semaphore::RedisSemaphore;
+use crate::stream_consumer::RedisStreamConsumer;
+use crate::types::{
+    HotKeyInfo, HotKeySampleMode, HotKeySampleOptions, KeyEvent, KeyEventWatchOptions, PushMessage, RedisClientConfig,
+    StreamConsumerOptions, StreamEntry,
+};
+
+/// Opens a RedisConnection on the libuv thread pool for `getConnectionAsync()`
+pub struct ConnectTask {
+    client: RedisClient,
+    timeout: Option<std::time::Duration>,
+}
+
+impl Task for ConnectTask {
+    type Output = redis::Connection;
+    type JsValue = RedisConnection;
+
+    fn compute(&mut self) -> Result<Self::Output> {
+        self.client.connect(self.timeout).map_err(|e| redis_error("Connect", e))
+    }
+
+    fn resolve(&mut self, _env: Env, output: Self::Output) -> Result<Self::JsValue> {
+        Ok(self.client.wrap_connection(Some(output)))
+    }
+}
 
 /// Redis Client for HarmonyOS
 ///
 /// This class represents a Redis client that can create connections to a Redis server.
 #[napi]
+#[derive(Clone)]
 pub struct RedisClient {
     inner: Client,
+    transient_retry: Option<TransientRetry>,
+    connection_retry: Option<ConnectionRetry>,
+    max_redirects: Option<u32>,
+    heartbeat: Option<std::time::Duration>,
+    lazy_connect: bool,
+    client_name: Option<String>,
+    connect_timeout: Option<std::time::Duration>,
+    response_timeout: Option<std::time::Duration>,
+    address: Option<AddressPreference>,
 }
 
 #[napi]
@@ -36,10 +88,19 @@ impl RedisClient {
     /// ```
     #[napi(constructor)]
     pub fn new(url: String) -> Result<Self> {
-        let client = Client::open(url.as_str()).map_err(|e| {
-            napi_ohos::Error::from_reason(format!("Failed to create Redis client: {}", e))
-        })?;
-        Ok(RedisClient { inner: client })
+        let client = Client::open(url.as_str()).map_err(|e| redis_error("Client creation", e))?;
+        Ok(RedisClient {
+            inner: client,
+            transient_retry: None,
+            connection_retry: None,
+            max_redirects: None,
+            heartbeat: None,
+            lazy_connect: false,
+            client_name: None,
+            connect_timeout: None,
+            response_timeout: None,
+            address: None,
+        })
     }
 
     /// Create a new Redis client from configuration object
@@ -56,7 +117,18 @@ impl RedisClient {
     ///   - `username`: Username for authentication (Redis 6.0+)
     ///   - `password`: Password for authentication
     ///   - `use_tls`: Use TLS/SSL connection (default: false)
-    ///   - `timeout_ms`: Connection timeout in milliseconds
+    ///   - `tls_insecure`: Skip server certificate verification (default: false)
+    ///   - `ca_cert_pem`: CA certificate to verify the server with (PEM)
+    ///   - `client_cert_pem` / `client_key_pem`: Client certificate and key for mutual TLS (PEM)
+    ///   - `connect_timeout_ms`: How long connecting may take (`timeout_ms` is an alias)
+    ///   - `response_timeout_ms`: How long commands wait for the server
+    ///   - `ip_family`: Connect over "ipv4" or "ipv6" only (default: "any")
+    ///   - `resolved_address`: IP address to connect to instead of resolving `host`
+    ///   - `max_retries`, `retry_base_delay_ms`, `retry_max_delay_ms`: Retry transient
+    ///     errors and connection failures with exponential backoff
+    ///   - `protocol`: RESP protocol version, 2 or 3 (default: 2)
+    ///   - `lazy_connect`: Connect on the first command instead of in `getConnection()` (default: false)
+    ///   - `client_name`: Name given to sync connections with CLIENT SETNAME
     ///
     /// # Returns
     /// A new RedisClient instance
@@ -97,11 +169,72 @@ impl RedisClient {
     /// ```
     #[napi(factory)]
     pub fn from_config(config: RedisClientConfig) -> Result<Self> {
-        let url = config.to_url();
-        let client = Client::open(url.as_str()).map_err(|e| {
-            napi_ohos::Error::from_reason(format!("Failed to create Redis client: {}", e))
-        })?;
-        Ok(RedisClient { inner: client })
+        let info = config
+            .to_url()
+            .as_str()
+            .into_connection_info()
+            .map_err(|e| redis_error("Client creation", e))?;
+        Self::build(info, config)
+    }
+
+    /// Create a client from a URL, with fields of a configuration overriding it
+    ///
+    /// Useful when the base URL comes from server-provided configuration. Any
+    /// field set in `config` replaces the corresponding part of the URL (host,
+    /// port, db, credentials, TLS); the other options apply as in `fromConfig()`.
+    ///
+    /// # Arguments
+    /// * `url` - Redis URL (e.g., "redis://:secret@10.0.0.5:6379/0")
+    /// * `config` - Fields to override
+    ///
+    /// # Returns
+    /// A new RedisClient instance
+    ///
+    /// # Example (ArkTS)
+    /// ```typescript
+    /// const client = RedisClient.fromUrlWithOverrides(remoteConfig.redisUrl, {
+    ///   db: 2,
+    ///   clientName: "orders-sync",
+    ///   connectTimeoutMs: 3000
+    /// });
+    /// ```
+    #[napi(factory)]
+    pub fn from_url_with_overrides(url: String, config: RedisClientConfig) -> Result<Self> {
+        let info = url
+            .as_str()
+            .into_connection_info()
+            .map_err(|e| redis_error("Client creation", e))?;
+        let info = override_address(info, &config)?;
+
+        let mut settings = info.redis_settings().clone();
+        if let Some(db) = config.db {
+            settings = settings.set_db(db as i64);
+        }
+        if let Some(username) = &config.username {
+            settings = settings.set_username(username);
+        }
+        if let Some(password) = &config.password {
+            settings = settings.set_password(password);
+        }
+        Self::build(info.set_redis_settings(settings), config)
+    }
+
+    /// Compute the cluster hash slot of a key locally
+    ///
+    /// Gives the same result as CLUSTER KEYSLOT without a round trip. Only
+    /// the hash tag is hashed if the key has one (`{user:1}:profile` and
+    /// `{user:1}:settings` share a slot).
+    ///
+    /// # Returns
+    /// The slot number (0-16383)
+    ///
+    /// # Example (ArkTS)
+    /// ```typescript
+    /// const slot = RedisClient.keySlot("mykey"); // 14687
+    /// ```
+    #[napi]
+    pub fn key_slot(key: String) -> u32 {
+        key_slot(key.as_bytes()) as u32
     }
 
     /// Get a synchronous connection to Redis
@@ -109,6 +242,9 @@ impl RedisClient {
     /// This method creates a new connection to the Redis server.
     /// The connection can be used to execute Redis commands.
     ///
+    /// With `lazyConnect`, this returns without connecting; connection
+    /// errors are reported by the first command instead.
+    ///
     /// # Returns
     /// A RedisConnection object
     ///
@@ -119,10 +255,121 @@ impl RedisClient {
     /// ```
     #[napi]
     pub fn get_connection(&self) -> Result<RedisConnection> {
-        let conn = self.inner.get_connection().map_err(|e| {
-            napi_ohos::Error::from_reason(format!("Failed to connect to Redis: {}", e))
-        })?;
-        Ok(RedisConnection::new(conn))
+        if self.lazy_connect {
+            return Ok(self.wrap_connection(None));
+        }
+        let conn = self.connect(None).map_err(|e| redis_error("Connect", e))?;
+        Ok(self.wrap_connection(Some(conn)))
+    }
+
+    /// Get a synchronous connection without blocking the ArkTS thread
+    ///
+    /// The TCP/TLS handshake runs on a worker thread; the returned connection
+    /// is the same as the one from `getConnection()`.
+    ///
+    /// # Arguments
+    /// * `timeout_ms` - Connection timeout in milliseconds (default: no timeout)
+    ///
+    /// # Returns
+    /// A Promise resolving to a RedisConnection
+    ///
+    /// # Example (ArkTS)
+    /// ```typescript
+    /// const conn = await client.getConnectionAsync(5000);
+    /// conn.set("mykey", "myvalue");
+    /// ```
+    #[napi]
+    pub fn get_connection_async(&self, timeout_ms: Option<u32>) -> AsyncTask<ConnectTask> {
+        AsyncTask::new(ConnectTask {
+            client: self.clone(),
+            timeout: timeout_ms.map(|ms| std::time::Duration::from_millis(ms as u64)),
+        })
+    }
+
+    /// Get an async connection to Redis
+    ///
+    /// The connection is established on a background thread, and every command
+    /// of the returned connection returns a Promise.
+    ///
+    /// # Arguments
+    /// * `command_timeout_ms` - How long commands wait for a reply (default: the client's `responseTimeoutMs`, or 500)
+    ///
+    /// # Returns
+    /// A Promise resolving to an AsyncRedisConnection
+    ///
+    /// # Example (ArkTS)
+    /// ```typescript
+    /// const client = new RedisClient("redis://127.0.0.1:6379");
+    /// const conn = await client.getAsyncConnection();
+    /// const value = await conn.get("mykey");
+    ///
+    /// // Slow mobile network: wait up to 3 seconds per command
+    /// const patient = await client.getAsyncConnection(3000);
+    /// ```
+    #[napi]
+    pub async fn get_async_connection(&self, command_timeout_ms: Option<u32>) -> Result<AsyncRedisConnection> {
+        let config = self.async_config(AsyncConnectionConfig::new(), command_timeout_ms)?;
+        let conn = self
+            .inner
+            .get_multiplexed_async_connection_with_config(&config)
+            .await
+            .map_err(|e| redis_error("Connect", e))?;
+        Ok(AsyncRedisConnection::new(self.inner.clone(), config, conn))
+    }
+
+    /// Get an async RESP3 connection that delivers server push frames
+    ///
+    /// The connection negotiates RESP3 (HELLO 3) and passes every push frame
+    /// to `on_push`: pub/sub messages from `subscribe()`, client tracking
+    /// invalidations, and a "disconnection" frame if the socket is lost.
+    /// Requires Redis 6 or later.
+    ///
+    /// # Arguments
+    /// * `on_push` - Called with each PushMessage on the ArkTS thread
+    /// * `command_timeout_ms` - How long commands wait for a reply (default: the client's `responseTimeoutMs`, or 500)
+    ///
+    /// # Returns
+    /// A Promise resolving to an AsyncRedisConnection
+    ///
+    /// # Example (ArkTS)
+    /// ```typescript
+    /// // Client-side cache invalidated by the server
+    /// const cache = new Map<string, string>();
+    /// const conn = await client.getPushConnection((msg) => {
+    ///   if (msg.kind === "invalidate") {
+    ///     msg.keys ? msg.keys.forEach((k) => cache.delete(k)) : cache.clear();
+    ///   }
+    /// });
+    /// await conn.cmd("CLIENT", ["TRACKING", "ON"]);
+    /// ```
+    #[napi]
+    pub async fn get_push_connection(
+        &self,
+        on_push: ThreadsafeFunction<PushMessage, Unknown<'static>, PushMessage, Status, false>,
+        command_timeout_ms: Option<u32>,
+    ) -> Result<AsyncRedisConnection> {
+        let info = self.inner.get_connection_info().clone();
+        let settings = info.redis_settings().clone().set_protocol(ProtocolVersion::RESP3);
+        let client = Client::open(info.set_redis_settings(settings)).map_err(|e| redis_error("Client creation", e))?;
+
+        // Blocking commands open connections with `config`, which must not
+        // deliver their push frames too
+        let config = self.async_config(AsyncConnectionConfig::new(), command_timeout_ms)?;
+        let push_config = config.clone().set_push_sender(move |info: PushInfo| {
+            match push_message(&info) {
+                Ok(message) => {
+                    on_push.call(message, ThreadsafeFunctionCallMode::NonBlocking);
+                }
+                Err(e) => report_background_error("Push connection", &e),
+            }
+            Ok::<(), ()>(())
+        });
+
+        let conn = client
+            .get_multiplexed_async_connection_with_config(&push_config)
+            .await
+            .map_err(|e| redis_error("Connect", e))?;
+        Ok(AsyncRedisConnection::new(client, config, conn))
     }
 
     /// Get a synchronous connection with timeout
@@ -141,13 +388,8 @@ impl RedisClient {
     #[napi]
     pub fn get_connection_with_timeout(&self, timeout_ms: u32) -> Result<RedisConnection> {
         let timeout = std::time::Duration::from_millis(timeout_ms as u64);
-        let conn = self
-            .inner
-            .get_connection_with_timeout(timeout)
-            .map_err(|e| {
-                napi_ohos::Error::from_reason(format!("Failed to connect to Redis: {}", e))
-            })?;
-        Ok(RedisConnection::new(conn))
+        let conn = self.connect(Some(timeout)).map_err(|e| redis_error("Connect", e))?;
+        Ok(self.wrap_connection(Some(conn)))
     }
 
     /// Get a connection and switch to specified database
@@ -216,9 +458,522 @@ impl RedisClient {
     /// ```
     #[napi]
     pub fn get_json_connection(&self) -> Result<RedisJsonConnection> {
-        let conn = self.inner.get_connection().map_err(|e| {
-            napi_ohos::Error::from_reason(format!("Failed to connect to Redis: {}", e))
-        })?;
+        let conn = self.connect(None).map_err(|e| redis_error("Connect", e))?;
         Ok(RedisJsonConnection::new(conn))
     }
+
+    /// Get an async JSON connection to Redis
+    ///
+    /// Like `getJsonConnection()`, but every command returns a Promise, so
+    /// large documents are read and written without blocking the ArkTS thread.
+    ///
+    /// # Returns
+    /// A Promise resolving to an AsyncRedisJsonConnection
+    ///
+    /// # Example (ArkTS)
+    /// ```typescript
+    /// const jsonConn = await client.getJsonConnectionAsync();
+    /// const doc = JSON.parse(await jsonConn.jsonGet("catalog", "$"));
+    /// ```
+    #[napi]
+    pub async fn get_json_connection_async(&self) -> Result<AsyncRedisJsonConnection> {
+        let config = self.async_config(AsyncConnectionConfig::new(), None)?;
+        let conn = self
+            .inner
+            .get_multiplexed_async_connection_with_config(&config)
+            .await
+            .map_err(|e| redis_error("Connect", e))?;
+        Ok(AsyncRedisJsonConnection::new(conn))
+    }
+
+    /// Get a delayed job queue
+    ///
+    /// The queue keeps its own connection for scheduling and polling; the
+    /// background worker started with `start()` opens another one.
+    ///
+    /// # Arguments
+    /// * `name` - Queue name, used as the hash tag of the queue keys
+    ///
+    /// # Returns
+    /// A RedisDelayedQueue object
+    ///
+    /// # Example (ArkTS)
+    /// ```typescript
+    /// const queue = client.getDelayedQueue("reminders");
+    /// queue.schedule("ping", 5000);
+    /// ```
+    #[napi]
+    pub fn get_delayed_queue(&self, name: String) -> Result<RedisDelayedQueue> {
+        let conn = self.connect(None).map_err(|e| redis_error("Connect", e))?;
+        Ok(RedisDelayedQueue::new(self.clone(), conn, &name))
+    }
+
+    /// Get a distributed semaphore
+    ///
+    /// # Arguments
+    /// * `name` - Semaphore name, used as the hash tag of its keys
+    /// * `limit` - Maximum number of concurrent holders
+    /// * `holder_ttl_ms` - Lease duration of a holder in milliseconds (default: 30000)
+    ///
+    /// # Returns
+    /// A RedisSemaphore object
+    ///
+    /// # Example (ArkTS)
+    /// ```typescript
+    /// const sem = client.getSemaphore("uploads", 3);
+    /// ```
+    #[napi]
+    pub fn get_semaphore(&self, name: String, limit: u32, holder_ttl_ms: Option<u32>) -> Result<RedisSemaphore> {
+        if limit == 0 {
+            return Err(napi_ohos::Error::from_reason("Semaphore limit must be at least 1"));
+        }
+        let conn = self.connect(None).map_err(|e| redis_error("Connect", e))?;
+        Ok(RedisSemaphore::new(conn, &name, limit, holder_ttl_ms.unwrap_or(30000)))
+    }
+
+    /// Get a presence tracker
+    ///
+    /// # Arguments
+    /// * `name` - Tracker name (e.g., a room or channel id), used as the hash tag of its keys
+    /// * `member_ttl_ms` - Time after the last heartbeat until a member is offline (default: 30000)
+    ///
+    /// # Returns
+    /// A PresenceTracker object
+    ///
+    /// # Example (ArkTS)
+    /// ```typescript
+    /// const presence = client.getPresenceTracker("chat-room-1", 15000);
+    /// ```
+    #[napi]
+    pub fn get_presence_tracker(&self, name: String, member_ttl_ms: Option<u32>) -> Result<PresenceTracker> {
+        let conn = self.connect(None).map_err(|e| redis_error("Connect", e))?;
+        Ok(PresenceTracker::new(
+            self.clone(),
+            conn,
+            self.db(),
+            &name,
+            member_ttl_ms.unwrap_or(30000).max(1),
+        ))
+    }
+
+    /// Watch keyspace events on keys matching a pattern
+    ///
+    /// Enables the needed `notify-keyspace-events` flags (keeping the ones
+    /// already set) and starts a background thread with its own subscriber
+    /// connection.
+    ///
+    /// # Arguments
+    /// * `pattern` - Glob-style key pattern (e.g., "session:*")
+    /// * `events` - Event names to deliver (e.g., ["set", "del", "expired"]); empty for all
+    /// * `callback` - Called with each KeyEvent on the ArkTS thread
+    /// * `options` - Optional watch options:
+    ///   - `configureServer`: enable notifications with CONFIG SET (default: true)
+    ///
+    /// # Returns
+    /// A KeyEventWatcher; call `stop()` to end the subscription
+    ///
+    /// # Example (ArkTS)
+    /// ```typescript
+    /// const watcher = client.watchKeyEvents("cart:*", ["hset", "del", "expired"], (e) => {
+    ///   console.log(`${e.event} on ${e.key} (db ${e.db})`);
+    /// });
+    ///
+    /// // aboutToDisappear()
+    /// watcher.stop();
+    /// ```
+    #[napi]
+    pub fn watch_key_events(
+        &self,
+        pattern: String,
+        events: Vec<String>,
+        callback: ThreadsafeFunction<KeyEvent, Unknown<'static>, KeyEvent, Status, false>,
+        options: Option<KeyEventWatchOptions>,
+    ) -> Result<KeyEventWatcher> {
+        KeyEventWatcher::start(self, self.db(), pattern, events, callback, options)
+    }
+
+    /// Consume a stream as a member of a consumer group
+    ///
+    /// Starts a background thread with its own connection that loops
+    /// XREADGROUP and calls the callback for each entry on the ArkTS thread.
+    /// An entry is acknowledged when the callback returns normally; if it
+    /// throws, the entry stays in the group's pending list.
+    ///
+    /// # Arguments
+    /// * `key` - The stream key
+    /// * `group` - Consumer group name
+    /// * `consumer` - Name of this consumer within the group
+    /// * `callback` - Synchronous function called with each StreamEntry
+    /// * `options` - Optional consumer options:
+    ///   - `count`: entries fetched per read (default: 10)
+    ///   - `blockMs`: XREADGROUP block time (default: 2000)
+    ///   - `createGroup`: create the group with MKSTREAM if missing (default: true)
+    ///   - `startId`: where a newly created group starts (default: "$")
+    ///   - `recoverPending`: redeliver this consumer's pending entries first (default: true)
+    ///
+    /// # Returns
+    /// A RedisStreamConsumer; call `stop()` to end consumption
+    ///
+    /// # Example (ArkTS)
+    /// ```typescript
+    /// const consumer = client.streamConsumer("events", "analytics", deviceId, (entry) => {
+    ///   if (!upload(entry.fields)) {
+    ///     throw new Error("upload failed"); // not acked, retried on next start
+    ///   }
+    /// }, { count: 50 });
+    /// ```
+    #[napi]
+    pub fn stream_consumer(
+        &self,
+        key: String,
+        group: String,
+        consumer: String,
+        callback: ThreadsafeFunction<StreamEntry, Unknown<'static>, StreamEntry, Status, false>,
+        options: Option<StreamConsumerOptions>,
+    ) -> Result<RedisStreamConsumer> {
+        RedisStreamConsumer::start(self, key, group, consumer, callback, options)
+    }
+
+    /// Answer RPC requests sent with `RedisConnection.rpcCall()`
+    ///
+    /// Starts a background thread that subscribes to the channel and calls
+    /// the handler for every request; the handler's return value is sent back
+    /// to the caller, and a thrown error is reported to the caller as a failure.
+    ///
+    /// # Arguments
+    /// * `channel` - RPC channel name
+    /// * `handler` - Synchronous function mapping the request payload to the response payload
+    ///
+    /// # Returns
+    /// A RedisRpcServer handle; call `stop()` to stop answering
+    ///
+    /// # Example (ArkTS)
+    /// ```typescript
+    /// const server = client.rpcServe("user.lookup", (payload) => {
+    ///   const { id } = JSON.parse(payload);
+    ///   return JSON.stringify(findUser(id));
+    /// });
+    /// ```
+    ///
+    /// # Note
+    /// The handler runs on the ArkTS thread that called `rpcServe()`. A blocking
+    /// `rpcCall()` on that same thread can't be answered by this server.
+    #[napi]
+    pub fn rpc_serve(
+        &self,
+        channel: String,
+        handler: ThreadsafeFunction<String, String, String, Status, false>,
+    ) -> Result<RedisRpcServer> {
+        RedisRpcServer::start(self, channel, handler)
+    }
+
+    /// Sample the most frequently accessed keys
+    ///
+    /// Sampling always runs on a dedicated connection so that MONITOR doesn't
+    /// take over a connection that is in use elsewhere.
+    ///
+    /// # Arguments
+    /// * `options` - Optional sampling options:
+    ///   - `mode`: `HotKeySampleMode.Lfu` (default) reads OBJECT FREQ of scanned keys and
+    ///     requires an LFU maxmemory-policy; `HotKeySampleMode.Monitor` observes live
+    ///     traffic through MONITOR
+    ///   - `top`: number of keys to return (default: 20)
+    ///   - `pattern`, `maxKeys`, `scanCount`: limit the keys inspected in Lfu mode
+    ///   - `durationMs`, `maxCommands`: limit the observation window in Monitor mode
+    ///
+    /// # Returns
+    /// Array of HotKeyInfo sorted by hits (hottest first)
+    ///
+    /// # Example (ArkTS)
+    /// ```typescript
+    /// import { HotKeySampleMode } from 'libredis_ohos.so';
+    ///
+    /// const hot = client.sampleHotKeys({ mode: HotKeySampleMode.Monitor, durationMs: 3000, top: 10 });
+    /// for (const item of hot) {
+    ///   console.log(`${item.key}: ${item.hits}`);
+    /// }
+    /// ```
+    ///
+    /// # Warning
+    /// MONITOR has a noticeable performance cost on busy servers, keep `durationMs` short.
+    #[napi]
+    pub fn sample_hot_keys(&self, options: Option<HotKeySampleOptions>) -> Result<Vec<HotKeyInfo>> {
+        let options = options.unwrap_or_default();
+        let top = options.top.unwrap_or(20) as usize;
+
+        let samples = match options.mode.unwrap_or(HotKeySampleMode::Lfu) {
+            HotKeySampleMode::Lfu => {
+                let pattern = options.pattern.as_deref().unwrap_or("*");
+                let max_keys = options.max_keys.unwrap_or(10_000) as usize;
+                let scan_count = options.scan_count.unwrap_or(1000);
+                self.get_connection()?
+                    .sample_lfu_frequencies(pattern, max_keys, scan_count)?
+            }
+            HotKeySampleMode::Monitor => {
+                let duration = std::time::Duration::from_millis(options.duration_ms.unwrap_or(1000) as u64);
+                self.sample_monitor_hits(duration, options.max_commands)?
+            }
+        };
+
+        let mut hot: Vec<HotKeyInfo> = samples
+            .into_iter()
+            .map(|(key, hits)| HotKeyInfo { key, hits })
+            .collect();
+        hot.sort_by(|a, b| b.hits.cmp(&a.hits).then_with(|| a.key.cmp(&b.key)));
+        hot.truncate(top);
+
+        Ok(hot)
+    }
+
+    // Create a client for `info` with the options of `config` that are not
+    // part of the URL
+    fn build(mut info: ConnectionInfo, config: RedisClientConfig) -> Result<Self> {
+        if let Some(protocol) = config.protocol {
+            let protocol = match protocol {
+                2 => ProtocolVersion::RESP2,
+                3 => ProtocolVersion::RESP3,
+                _ => {
+                    return Err(napi_ohos::Error::new(
+                        Status::InvalidArg,
+                        format!("Unsupported RESP protocol version: {} (expected 2 or 3)", protocol),
+                    ))
+                }
+            };
+            let settings = info.redis_settings().clone().set_protocol(protocol);
+            info = info.set_redis_settings(settings);
+        }
+        if let Some(ms) = config.tcp_keepalive_ms {
+            let keepalive = TcpKeepalive::new().with_time(std::time::Duration::from_millis(ms as u64));
+            info = info.set_tcp_settings(TcpSettings::default().set_keepalive(keepalive));
+        }
+        let client = match tls_certificates(&config)? {
+            Some(certs) => Client::build_with_tls(info, certs),
+            None => Client::open(info),
+        }
+        .map_err(|e| redis_error("Client creation", e))?;
+        let address = AddressPreference::from_config(&config)?;
+        Ok(RedisClient {
+            inner: client,
+            transient_retry: config.transient_retry_config().as_ref().map(TransientRetry::from_config),
+            connection_retry: config.connection_retry_config().as_ref().map(ConnectionRetry::from_config),
+            max_redirects: config.max_redirects,
+            heartbeat: config
+                .heartbeat_interval_ms
+                .map(|ms| std::time::Duration::from_millis(ms.max(1) as u64)),
+            lazy_connect: config.lazy_connect.unwrap_or(false),
+            client_name: config.client_name,
+            connect_timeout: config.connect_timeout_ms.or(config.timeout_ms).and_then(socket_timeout),
+            response_timeout: config.response_timeout_ms.and_then(socket_timeout),
+            address,
+        })
+    }
+
+    // Database index the client's connections start in
+    fn db(&self) -> i64 {
+        self.inner.get_connection_info().redis_settings().db()
+    }
+
+    // Apply the client's connect and response timeouts to an async connection;
+    // `command_timeout_ms` overrides the response timeout
+    fn async_config(
+        &self,
+        mut config: AsyncConnectionConfig,
+        command_timeout_ms: Option<u32>,
+    ) -> Result<AsyncConnectionConfig> {
+        if self.connect_timeout.is_some() {
+            config = config.set_connection_timeout(self.connect_timeout);
+        }
+        if let Some(address) = self.address {
+            config = config.set_dns_resolver(address);
+        }
+        let response_timeout = match command_timeout_ms {
+            Some(timeout_ms) => Some(command_timeout(timeout_ms)?),
+            None => self.response_timeout,
+        };
+        if response_timeout.is_some() {
+            config = config.set_response_timeout(response_timeout);
+        }
+        Ok(config)
+    }
+
+    // Open a sync socket to the preferred address; `timeout` overrides the
+    // connect timeout
+    fn open(&self, timeout: Option<std::time::Duration>) -> redis::RedisResult<redis::Connection> {
+        let timeout = timeout.or(self.connect_timeout);
+        if let Some(address) = &self.address {
+            return address.connect(self.inner.get_connection_info(), timeout);
+        }
+        match timeout {
+            Some(timeout) => self.inner.get_connection_with_timeout(timeout),
+            None => self.inner.get_connection(),
+        }
+    }
+
+    // Open a sync connection with the configured timeouts and name; `timeout`
+    // overrides the connect timeout
+    pub(crate) fn connect(&self, timeout: Option<std::time::Duration>) -> redis::RedisResult<redis::Connection> {
+        let mut conn = self.open(timeout)?;
+        if self.response_timeout.is_some() {
+            conn.set_read_timeout(self.response_timeout)?;
+            conn.set_write_timeout(self.response_timeout)?;
+        }
+        if let Some(name) = &self.client_name {
+            redis::cmd("CLIENT").arg("SETNAME").arg(name).query::<()>(&mut conn)?;
+        }
+        Ok(conn)
+    }
+
+    // Apply the client's retry and redirect settings to a new sync connection,
+    // or to one that connects on its first command if `conn` is None
+    fn wrap_connection(&self, conn: Option<redis::Connection>) -> RedisConnection {
+        let redirects = self
+            .max_redirects
+            .map(|hops| RedirectPolicy::new(hops, self.inner.get_connection_info().clone()));
+        // Name and timeouts are restored by the connection itself
+        let client = self.clone();
+        let reconnect: Reconnect = Arc::new(move || client.open(None));
+
+        let conn = match conn {
+            Some(conn) => RedisConnection::new(conn, self.db(), self.transient_retry),
+            None => RedisConnection::lazy(self.db(), self.transient_retry),
+        };
+        conn.with_redirects(redirects)
+            .with_client_name(self.client_name.clone())
+            .with_response_timeout(self.response_timeout)
+            .with_reconnect(reconnect, self.connection_retry.is_some())
+            .with_connection_retry(self.connection_retry)
+            .with_heartbeat(self.heartbeat)
+    }
+
+    // Helper function to count key accesses seen through MONITOR
+    fn sample_monitor_hits(
+        &self,
+        duration: std::time::Duration,
+        max_commands: Option<u32>,
+    ) -> Result<Vec<(String, i64)>> {
+        let mut conn = self.connect(None).map_err(|e| redis_error("Connect", e))?;
+
+        let _: () = redis::cmd("MONITOR")
+            .query(&mut conn)
+            .map_err(|e| redis_error("MONITOR", e))?;
+
+        let deadline = std::time::Instant::now() + duration;
+        let mut hits: HashMap<String, i64> = HashMap::new();
+        let mut observed = 0u32;
+
+        loop {
+            let remaining = deadline.saturating_duration_since(std::time::Instant::now());
+            if remaining.is_zero() || max_commands.is_some_and(|max| observed >= max) {
+                break;
+            }
+
+            conn.set_read_timeout(Some(remaining)).map_err(|e| redis_error("MONITOR", e))?;
+
+            let line = match conn.recv_response() {
+                Ok(redis::Value::SimpleString(line)) => line,
+                Ok(_) => continue,
+                Err(e) if e.is_timeout() => break,
+                Err(e) => {
+                    return Err(redis_error("MONITOR", e));
+                }
+            };
+
+            observed += 1;
+            if let Some(key) = monitor_line_key(&line) {
+                *hits.entry(key).or_insert(0) += 1;
+            }
+        }
+
+        // The connection is stuck in MONITOR mode and is dropped here
+        Ok(hits.into_iter().collect())
+    }
+}
+
+// Apply the host, port and TLS fields of `config` to an address parsed from a URL
+fn override_address(info: ConnectionInfo, config: &RedisClientConfig) -> Result<ConnectionInfo> {
+    let (host, port, tls, insecure) = match info.addr() {
+        ConnectionAddr::Tcp(host, port) => (host.clone(), *port, false, false),
+        ConnectionAddr::TcpTls { host, port, insecure, .. } => (host.clone(), *port, true, *insecure),
+        _ if config.host.is_none()
+            && config.port.is_none()
+            && config.use_tls.is_none()
+            && config.tls_insecure.is_none() =>
+        {
+            return Ok(info)
+        }
+        _ => {
+            return Err(napi_ohos::Error::new(
+                Status::InvalidArg,
+                "Host, port and TLS cannot be overridden for a Unix socket URL",
+            ))
+        }
+    };
+
+    let host = config.host.clone().unwrap_or(host);
+    let port = config.port.unwrap_or(port);
+    let addr = if config.use_tls.unwrap_or(tls) {
+        ConnectionAddr::TcpTls {
+            host,
+            port,
+            insecure: config.tls_insecure.unwrap_or(insecure),
+            tls_params: None,
+        }
+    } else {
+        ConnectionAddr::Tcp(host, port)
+    };
+    Ok(info.set_addr(addr))
+}
+
+// Collect the PEM certificates of the config, if any were given
+fn tls_certificates(config: &RedisClientConfig) -> Result<Option<TlsCertificates>> {
+    let client_tls = match (&config.client_cert_pem, &config.client_key_pem) {
+        (Some(cert), Some(key)) => Some(ClientTlsConfig {
+            client_cert: cert.as_bytes().to_vec(),
+            client_key: key.as_bytes().to_vec(),
+        }),
+        (None, None) => None,
+        _ => {
+            return Err(napi_ohos::Error::new(
+                Status::InvalidArg,
+                "clientCertPem and clientKeyPem must be given together",
+            ))
+        }
+    };
+    let root_cert = config.ca_cert_pem.as_ref().map(|pem| pem.as_bytes().to_vec());
+
+    if client_tls.is_none() && root_cert.is_none() {
+        return Ok(None);
+    }
+    Ok(Some(TlsCertificates { client_tls, root_cert }))
+}
+
+// Extract the first argument (usually the key) from a MONITOR line
+// Format: 1339518083.107412 [0 127.0.0.1:60866] "set" "foo" "bar"
+fn monitor_line_key(line: &str) -> Option<String> {
+    let (_, args) = line.split_once("] ")?;
+    let mut chars = args.chars();
+    let mut tokens = Vec::with_capacity(2);
+
+    while tokens.len() < 2 {
+        // Skip to the opening quote of the next token
+        if !chars.by_ref().any(|c| c == '"') {
+            return None;
+        }
+
+        let mut token = String::new();
+        while let Some(c) = chars.next() {
+            match c {
+                '\\' => {
+                    if let Some(escaped) = chars.next() {
+                        token.push(escaped);
+                    }
+                }
+                '"' => break,
+                _ => token.push(c),
+            }
+        }
+        tokens.push(token);
+    }
+
+    tokens.pop()
 }
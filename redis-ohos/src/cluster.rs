@@ -0,0 +1,502 @@
+// Redis Cluster client for HarmonyOS NAPI
+//
+// Wraps redis-rs's cluster client. The connection discovers the slot map from
+// the configured nodes, routes each command to the node owning its key's slot
+// and follows MOVED/ASK redirections itself.
+
+use napi_derive_ohos::napi;
+use napi_ohos::bindgen_prelude::*;
+use redis::cluster::{ClusterClient, ClusterClientBuilder, ClusterConnection};
+use redis::cluster_routing::{RoutingInfo, SingleNodeRoutingInfo};
+use redis::{Commands, FromRedisValue, RedisResult};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+use crate::cluster_pipeline::RedisClusterPipeline;
+use crate::connection::{command_from_args, redis_value_to_json_value};
+use crate::error::redis_error;
+use crate::types::{ClusterReadFrom, RedisClusterConfig, RedisValue};
+
+/// Number of hash slots of a Redis Cluster
+const SLOT_COUNT: u16 = 16384;
+
+/// How often `readFrom: "nearest"` measures the node round trips again
+const NEAREST_REFRESH_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Hash slot of a key, as computed by the cluster (CRC16-XMODEM modulo 16384)
+///
+/// Only the part between the first `{` and the following `}` is hashed when
+/// it is not empty, so keys sharing a hash tag land in the same slot.
+pub(crate) fn key_slot(key: &[u8]) -> u16 {
+    let hashed = key
+        .iter()
+        .position(|&b| b == b'{')
+        .and_then(|open| {
+            let close = key[open + 1..].iter().position(|&b| b == b'}')?;
+            Some(&key[open + 1..open + 1 + close])
+        })
+        .filter(|tag| !tag.is_empty())
+        .unwrap_or(key);
+
+    let mut crc: u16 = 0;
+    for &byte in hashed {
+        crc ^= (byte as u16) << 8;
+        for _ in 0..8 {
+            crc = if crc & 0x8000 != 0 { (crc << 1) ^ 0x1021 } else { crc << 1 };
+        }
+    }
+    crc % SLOT_COUNT
+}
+
+/// Node with the lowest round trip for each slot range, for `readFrom: "nearest"`
+struct NearestNodes {
+    /// Slot ranges keyed by their last slot: (first slot, host, port)
+    ranges: BTreeMap<u16, (u16, String, u16)>,
+    measured_at: Instant,
+}
+
+impl NearestNodes {
+    /// Read the slot map with CLUSTER SLOTS and PING every node it lists
+    fn measure(conn: &mut ClusterConnection) -> RedisResult<Self> {
+        let slots: Vec<Vec<redis::Value>> = redis::cmd("CLUSTER").arg("SLOTS").query(conn)?;
+
+        let mut rtts: HashMap<(String, u16), Option<Duration>> = HashMap::new();
+        let mut ranges = BTreeMap::new();
+        for range in slots {
+            let mut items = range.into_iter();
+            let (Some(start), Some(end)) = (items.next(), items.next()) else {
+                continue;
+            };
+            let start: u16 = redis::from_redis_value(start)?;
+            let end: u16 = redis::from_redis_value(end)?;
+
+            // The master, then its replicas: [host, port, id, ...]
+            let mut nearest: Option<(Duration, String, u16)> = None;
+            for node in items {
+                let redis::Value::Array(node) = node else {
+                    continue;
+                };
+                let (Some(host), Some(port)) = (node.first(), node.get(1)) else {
+                    continue;
+                };
+                let host: String = redis::from_redis_value_ref(host)?;
+                let port: u16 = redis::from_redis_value_ref(port)?;
+                // An empty host is an endpoint the node doesn't know
+                if host.is_empty() {
+                    continue;
+                }
+
+                let rtt = *rtts
+                    .entry((host.clone(), port))
+                    .or_insert_with(|| ping_rtt(conn, &host, port));
+                if let Some(rtt) = rtt.filter(|rtt| nearest.as_ref().is_none_or(|(best, ..)| rtt < best)) {
+                    nearest = Some((rtt, host, port));
+                }
+            }
+            if let Some((_, host, port)) = nearest {
+                ranges.insert(end, (start, host, port));
+            }
+        }
+
+        Ok(NearestNodes {
+            ranges,
+            measured_at: Instant::now(),
+        })
+    }
+
+    /// Address of the nearest node serving `slot`
+    fn node_for(&self, slot: u16) -> Option<(&str, u16)> {
+        let (_, (start, host, port)) = self.ranges.range(slot..).next()?;
+        (*start <= slot).then_some((host.as_str(), *port))
+    }
+}
+
+/// Best of three PING round trips to one node, None if it can't be reached
+fn ping_rtt(conn: &mut ClusterConnection, host: &str, port: u16) -> Option<Duration> {
+    let routing = RoutingInfo::SingleNode(SingleNodeRoutingInfo::ByAddress {
+        host: host.to_string(),
+        port,
+    });
+    (0..3)
+        .filter_map(|_| {
+            let started = Instant::now();
+            conn.route_command(&redis::cmd("PING"), routing.clone())
+                .ok()
+                .map(|_| started.elapsed())
+        })
+        .min()
+}
+
+/// Redis Cluster client for HarmonyOS
+///
+/// # Example (ArkTS)
+/// ```typescript
+/// import { RedisClusterClient } from 'libredis_ohos.so';
+///
+/// const cluster = new RedisClusterClient(["redis://10.0.0.1:7000", "redis://10.0.0.2:7000"]);
+/// const conn = cluster.getConnection();
+/// conn.set("user:1", "Alice"); // sent to the node owning the slot of "user:1"
+/// ```
+#[napi]
+pub struct RedisClusterClient {
+    inner: ClusterClient,
+    /// Route single-key reads to the node with the lowest round trip
+    nearest: bool,
+}
+
+impl RedisClusterClient {
+    fn build(builder: ClusterClientBuilder, nearest: bool) -> Result<Self> {
+        let client = builder.build().map_err(|e| redis_error("Cluster client creation", e))?;
+        Ok(RedisClusterClient { inner: client, nearest })
+    }
+}
+
+#[napi]
+impl RedisClusterClient {
+    /// Create a cluster client from node URLs
+    ///
+    /// # Arguments
+    /// * `nodes` - URLs of one or more cluster nodes (e.g., `redis://10.0.0.1:7000`);
+    ///   the rest of the cluster is discovered from them
+    #[napi(constructor)]
+    pub fn new(nodes: Vec<String>) -> Result<Self> {
+        Self::build(ClusterClientBuilder::new(nodes), false)
+    }
+
+    /// Create a cluster client from a configuration object
+    ///
+    /// # Arguments
+    /// * `config` - Node URLs plus optional credentials, timeouts, retry count
+    ///   and read routing policy
+    #[napi(factory)]
+    pub fn from_config(config: RedisClusterConfig) -> Result<Self> {
+        let mut builder = ClusterClientBuilder::new(config.nodes);
+        if let Some(username) = config.username {
+            builder = builder.username(username);
+        }
+        if let Some(password) = config.password {
+            builder = builder.password(password);
+        }
+        if let Some(ms) = config.connection_timeout_ms {
+            builder = builder.connection_timeout(Duration::from_millis(ms as u64));
+        }
+        if let Some(ms) = config.response_timeout_ms {
+            builder = builder.response_timeout(Duration::from_millis(ms as u64));
+        }
+        if let Some(retries) = config.retries {
+            builder = builder.retries(retries);
+        }
+        // Replica connections need READONLY for "nearest" too
+        let read_from = config.read_from.unwrap_or(ClusterReadFrom::Master);
+        if read_from != ClusterReadFrom::Master {
+            builder = builder.read_from_replicas();
+        }
+        Self::build(builder, read_from == ClusterReadFrom::Nearest)
+    }
+
+    /// Get a synchronous connection to the cluster
+    ///
+    /// # Returns
+    /// A RedisClusterConnection that routes every command to the right node
+    #[napi]
+    pub fn get_connection(&self) -> Result<RedisClusterConnection> {
+        let mut conn = self.inner.get_connection().map_err(|e| redis_error("Connect", e))?;
+        let nearest = self
+            .nearest
+            .then(|| NearestNodes::measure(&mut conn))
+            .transpose()
+            .map_err(|e| redis_error("Node latency", e))?;
+        Ok(RedisClusterConnection {
+            inner: Arc::new(Mutex::new(conn)),
+            nearest,
+        })
+    }
+}
+
+/// Synchronous connection to a Redis Cluster
+///
+/// Created with `RedisClusterClient.getConnection()`. Keys used together in
+/// one multi-key command must hash to the same slot; use hash tags such as
+/// `{user:1}:profile` and `{user:1}:settings` to group them.
+#[napi]
+pub struct RedisClusterConnection {
+    inner: Arc<Mutex<ClusterConnection>>,
+    /// Nearest node per slot range, with `readFrom: "nearest"`
+    nearest: Option<NearestNodes>,
+}
+
+impl RedisClusterConnection {
+    /// Lock the connection, which is shared with the pipelines created from it
+    fn conn(&self) -> MutexGuard<'_, ClusterConnection> {
+        self.inner.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Send a read-only command about `key`
+    ///
+    /// With `readFrom: "nearest"` it goes to the nearest node serving the
+    /// key's slot; MOVED replies after a resharding are still followed.
+    fn read<T: FromRedisValue>(&mut self, cmd: &redis::Cmd, key: &str) -> RedisResult<T> {
+        let mut conn = self.inner.lock().unwrap_or_else(|e| e.into_inner());
+        let Some(nearest) = self.nearest.as_mut() else {
+            return cmd.query(&mut *conn);
+        };
+
+        if nearest.measured_at.elapsed() >= NEAREST_REFRESH_INTERVAL {
+            match NearestNodes::measure(&mut conn) {
+                Ok(fresh) => *nearest = fresh,
+                // Keep the previous picks until the next attempt
+                Err(_) => nearest.measured_at = Instant::now(),
+            }
+        }
+        let Some((host, port)) = nearest.node_for(key_slot(key.as_bytes())) else {
+            return cmd.query(&mut *conn);
+        };
+        let routing = RoutingInfo::SingleNode(SingleNodeRoutingInfo::ByAddress {
+            host: host.to_string(),
+            port,
+        });
+        Ok(redis::from_redis_value(conn.route_command(cmd, routing)?)?)
+    }
+}
+
+#[napi]
+impl RedisClusterConnection {
+    // ==================== String Commands ====================
+
+    /// SET command - Set a string value
+    #[napi]
+    pub fn set(&mut self, key: String, value: String) -> Result<()> {
+        self.conn().set(key, value).map_err(|e| redis_error("SET", e))
+    }
+
+    /// GET command - Get a string value
+    ///
+    /// # Returns
+    /// The value as a string, or null if key doesn't exist
+    #[napi]
+    pub fn get(&mut self, key: String) -> Result<Option<String>> {
+        self.read(redis::cmd("GET").arg(&key), &key)
+            .map_err(|e| redis_error("GET", e))
+    }
+
+    /// SETEX command - Set key with expiration in seconds
+    #[napi]
+    pub fn setex(&mut self, key: String, value: String, seconds: u32) -> Result<()> {
+        self.conn()
+            .set_ex(key, value, seconds as u64)
+            .map_err(|e| redis_error("SETEX", e))
+    }
+
+    /// MGET command - Get multiple values
+    ///
+    /// Keys on different slots are fetched from their own nodes.
+    ///
+    /// # Returns
+    /// Array of values (null for non-existent keys)
+    #[napi]
+    pub fn mget(&mut self, keys: Vec<String>) -> Result<Vec<Option<String>>> {
+        redis::cmd("MGET")
+            .arg(keys)
+            .query(&mut *self.conn())
+            .map_err(|e| redis_error("MGET", e))
+    }
+
+    /// INCRBY command - Increment integer value
+    ///
+    /// # Arguments
+    /// * `delta` - Amount to add (default: 1)
+    #[napi]
+    pub fn incr(&mut self, key: String, delta: Option<i64>) -> Result<i64> {
+        self.conn()
+            .incr(key, delta.unwrap_or(1))
+            .map_err(|e| redis_error("INCRBY", e))
+    }
+
+    // ==================== Key Commands ====================
+
+    /// DEL command - Delete keys
+    ///
+    /// # Returns
+    /// Number of keys deleted
+    #[napi]
+    pub fn del(&mut self, keys: Vec<String>) -> Result<i64> {
+        redis::cmd("DEL")
+            .arg(keys)
+            .query(&mut *self.conn())
+            .map_err(|e| redis_error("DEL", e))
+    }
+
+    /// UNLINK command - Delete keys, reclaiming their memory in the background
+    ///
+    /// # Returns
+    /// Number of keys unlinked
+    #[napi]
+    pub fn unlink(&mut self, keys: Vec<String>) -> Result<i64> {
+        redis::cmd("UNLINK")
+            .arg(keys)
+            .query(&mut *self.conn())
+            .map_err(|e| redis_error("UNLINK", e))
+    }
+
+    /// EXISTS command - Check if a key exists
+    #[napi]
+    pub fn exists(&mut self, key: String) -> Result<bool> {
+        self.read(redis::cmd("EXISTS").arg(&key), &key)
+            .map_err(|e| redis_error("EXISTS", e))
+    }
+
+    /// EXPIRE command - Set a key's time to live in seconds
+    ///
+    /// # Returns
+    /// true if the timeout was set, false if key doesn't exist
+    #[napi]
+    pub fn expire(&mut self, key: String, seconds: i64) -> Result<bool> {
+        self.conn().expire(key, seconds).map_err(|e| redis_error("EXPIRE", e))
+    }
+
+    /// TTL command - Get the time to live of a key in seconds
+    ///
+    /// # Returns
+    /// TTL in seconds, -1 if no expiration, -2 if key doesn't exist
+    #[napi]
+    pub fn ttl(&mut self, key: String) -> Result<i64> {
+        self.read(redis::cmd("TTL").arg(&key), &key)
+            .map_err(|e| redis_error("TTL", e))
+    }
+
+    // ==================== Hash Commands ====================
+
+    /// HSET command - Set a hash field
+    ///
+    /// # Returns
+    /// true if the field is new, false if it was updated
+    #[napi]
+    pub fn hset(&mut self, key: String, field: String, value: String) -> Result<bool> {
+        self.conn().hset(key, field, value).map_err(|e| redis_error("HSET", e))
+    }
+
+    /// HGET command - Get a hash field
+    #[napi]
+    pub fn hget(&mut self, key: String, field: String) -> Result<Option<String>> {
+        self.read(redis::cmd("HGET").arg(&key).arg(field), &key)
+            .map_err(|e| redis_error("HGET", e))
+    }
+
+    /// HGETALL command - Get all fields and values of a hash
+    #[napi]
+    pub fn hgetall(&mut self, key: String) -> Result<HashMap<String, String>> {
+        self.read(redis::cmd("HGETALL").arg(&key), &key)
+            .map_err(|e| redis_error("HGETALL", e))
+    }
+
+    /// HDEL command - Delete hash fields
+    ///
+    /// # Returns
+    /// Number of fields removed
+    #[napi]
+    pub fn hdel(&mut self, key: String, fields: Vec<String>) -> Result<i64> {
+        self.conn().hdel(key, fields).map_err(|e| redis_error("HDEL", e))
+    }
+
+    // ==================== List and Set Commands ====================
+
+    /// RPUSH command - Append values to a list
+    ///
+    /// # Returns
+    /// Length of the list after the push
+    #[napi]
+    pub fn rpush(&mut self, key: String, values: Vec<String>) -> Result<i64> {
+        self.conn().rpush(key, values).map_err(|e| redis_error("RPUSH", e))
+    }
+
+    /// LRANGE command - Get a range of list elements
+    #[napi]
+    pub fn lrange(&mut self, key: String, start: i32, stop: i32) -> Result<Vec<String>> {
+        self.read(redis::cmd("LRANGE").arg(&key).arg(start).arg(stop), &key)
+            .map_err(|e| redis_error("LRANGE", e))
+    }
+
+    /// SADD command - Add members to a set
+    ///
+    /// # Returns
+    /// Number of members added
+    #[napi]
+    pub fn sadd(&mut self, key: String, members: Vec<String>) -> Result<i64> {
+        self.conn().sadd(key, members).map_err(|e| redis_error("SADD", e))
+    }
+
+    /// SMEMBERS command - Get all members of a set
+    #[napi]
+    pub fn smembers(&mut self, key: String) -> Result<Vec<String>> {
+        self.read(redis::cmd("SMEMBERS").arg(&key), &key)
+            .map_err(|e| redis_error("SMEMBERS", e))
+    }
+
+    // ==================== Utility Commands ====================
+
+    /// PING command - Check that the cluster is reachable
+    #[napi]
+    pub fn ping(&mut self) -> Result<String> {
+        redis::cmd("PING")
+            .query(&mut *self.conn())
+            .map_err(|e| redis_error("PING", e))
+    }
+
+    // ==================== Pipelines ====================
+
+    /// Create a pipeline whose commands are batched per node
+    ///
+    /// # Returns
+    /// A RedisClusterPipeline sharing this connection
+    ///
+    /// # Example (ArkTS)
+    /// ```typescript
+    /// const pipe = clusterConn.pipeline();
+    /// for (const id of userIds) {
+    ///   pipe.hgetall(`user:${id}`);
+    /// }
+    /// const profiles = pipe.execute();
+    /// ```
+    #[napi]
+    pub fn pipeline(&self) -> RedisClusterPipeline {
+        RedisClusterPipeline::new(self.inner.clone())
+    }
+
+    // ==================== Generic Command Interface ====================
+
+    /// Execute a raw Redis command
+    ///
+    /// The command is routed by its first key; keyless commands go to a
+    /// random node.
+    ///
+    /// # Returns
+    /// JSON string representation of the Redis response
+    #[napi]
+    pub fn cmd(&mut self, command: String, args: Vec<String>) -> Result<String> {
+        let result: redis::Value = redis::cmd(&command)
+            .arg(args)
+            .query(&mut *self.conn())
+            .map_err(|e| redis_error(&format!("CMD {}", command), e))?;
+
+        let json = redis_value_to_json_value(&result)?;
+        serde_json::to_string(&json)
+            .map_err(|e| napi_ohos::Error::from_reason(format!("JSON serialization failed: {}", e)))
+    }
+
+    /// Execute a command given as a single argument array
+    ///
+    /// # Arguments
+    /// * `args` - Command name followed by its arguments
+    ///
+    /// # Returns
+    /// The reply, tagged with its RESP kind
+    #[napi]
+    pub fn execute(&mut self, args: Vec<String>) -> Result<RedisValue> {
+        let cmd = command_from_args(&args)?;
+        let result: redis::Value = cmd
+            .query(&mut *self.conn())
+            .map_err(|e| redis_error(&format!("CMD {}", args[0]), e))?;
+        Ok(RedisValue::from_redis_value(&result, false))
+    }
+}
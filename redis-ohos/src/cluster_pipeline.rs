@@ -0,0 +1,166 @@
+// Cluster pipeline for HarmonyOS NAPI
+//
+// Commands are queued on a RedisClusterPipeline and grouped per node owning
+// their slot when `execute()` is called. Each node's batch is written before
+// any reply is read, so the batches are in flight in parallel, and the
+// replies are put back in queue order.
+
+use napi_derive_ohos::napi;
+use napi_ohos::bindgen_prelude::*;
+use redis::cluster::{ClusterConnection, ClusterPipeline};
+use redis::Value;
+use std::sync::{Arc, Mutex};
+
+use crate::error::redis_error;
+use crate::types::RedisValue;
+
+/// Batch of commands sent to a cluster with one round trip per node
+///
+/// Created with `RedisClusterConnection.pipeline()`. Command methods return
+/// the pipeline, so calls can be chained. Commands whose node changed
+/// (MOVED/ASK) are retried individually after the slot map is refreshed.
+///
+/// Transactions and commands spanning nodes (MGET, MSET, KEYS, ...) are not
+/// supported in a cluster pipeline.
+///
+/// # Example (ArkTS)
+/// ```typescript
+/// const results = clusterConn.pipeline()
+///   .set("user:1:name", "Alice")
+///   .set("user:2:name", "Bob")
+///   .incr("users:count")
+///   .execute();
+/// ```
+#[napi]
+pub struct RedisClusterPipeline {
+    conn: Arc<Mutex<ClusterConnection>>,
+    pipe: ClusterPipeline,
+}
+
+impl RedisClusterPipeline {
+    pub(crate) fn new(conn: Arc<Mutex<ClusterConnection>>) -> Self {
+        RedisClusterPipeline {
+            conn,
+            pipe: ClusterPipeline::new(),
+        }
+    }
+}
+
+#[napi]
+impl RedisClusterPipeline {
+    /// Queue any Redis command
+    ///
+    /// # Arguments
+    /// * `command` - The Redis command name (e.g., "ZADD")
+    /// * `args` - Array of command arguments
+    #[napi]
+    pub fn cmd<'env>(&mut self, this: This<'env>, command: String, args: Vec<String>) -> This<'env> {
+        self.pipe.cmd(&command).arg(args);
+        this
+    }
+
+    /// Queue a SET command
+    #[napi]
+    pub fn set<'env>(&mut self, this: This<'env>, key: String, value: String) -> This<'env> {
+        self.pipe.set(key, value);
+        this
+    }
+
+    /// Queue a GET command
+    #[napi]
+    pub fn get<'env>(&mut self, this: This<'env>, key: String) -> This<'env> {
+        self.pipe.get(key);
+        this
+    }
+
+    /// Queue a DEL command for one key
+    #[napi]
+    pub fn del<'env>(&mut self, this: This<'env>, key: String) -> This<'env> {
+        self.pipe.del(key);
+        this
+    }
+
+    /// Queue an INCRBY command
+    ///
+    /// # Arguments
+    /// * `delta` - Amount to add (default: 1)
+    #[napi]
+    pub fn incr<'env>(&mut self, this: This<'env>, key: String, delta: Option<i64>) -> This<'env> {
+        self.pipe.incr(key, delta.unwrap_or(1));
+        this
+    }
+
+    /// Queue an EXPIRE command
+    #[napi]
+    pub fn expire<'env>(&mut self, this: This<'env>, key: String, seconds: i64) -> This<'env> {
+        self.pipe.expire(key, seconds);
+        this
+    }
+
+    /// Queue an HSET command
+    #[napi]
+    pub fn hset<'env>(&mut self, this: This<'env>, key: String, field: String, value: String) -> This<'env> {
+        self.pipe.hset(key, field, value);
+        this
+    }
+
+    /// Queue an HGET command
+    #[napi]
+    pub fn hget<'env>(&mut self, this: This<'env>, key: String, field: String) -> This<'env> {
+        self.pipe.hget(key, field);
+        this
+    }
+
+    /// Queue an HGETALL command
+    #[napi]
+    pub fn hgetall<'env>(&mut self, this: This<'env>, key: String) -> This<'env> {
+        self.pipe.hgetall(key);
+        this
+    }
+
+    /// Queue an RPUSH command
+    #[napi]
+    pub fn rpush<'env>(&mut self, this: This<'env>, key: String, values: Vec<String>) -> This<'env> {
+        self.pipe.rpush(key, values);
+        this
+    }
+
+    /// Queue an SADD command
+    #[napi]
+    pub fn sadd<'env>(&mut self, this: This<'env>, key: String, members: Vec<String>) -> This<'env> {
+        self.pipe.sadd(key, members);
+        this
+    }
+
+    /// Queue a ZADD command for one member
+    #[napi]
+    pub fn zadd<'env>(&mut self, this: This<'env>, key: String, member: String, score: f64) -> This<'env> {
+        self.pipe.zadd(key, member, score);
+        this
+    }
+
+    /// Number of queued commands
+    #[napi(getter)]
+    pub fn length(&self) -> u32 {
+        self.pipe.cmd_iter().count() as u32
+    }
+
+    /// Send all queued commands, one batch per node
+    ///
+    /// The pipeline is emptied and can be reused for the next batch. If the
+    /// server rejects any command the whole call fails with an error listing
+    /// the failed commands; replies of the other commands are not returned.
+    ///
+    /// # Returns
+    /// Each reply, in queue order
+    #[napi]
+    pub fn execute(&mut self) -> Result<Vec<RedisValue>> {
+        let pipe = std::mem::take(&mut self.pipe);
+        let mut conn = self.conn.lock().unwrap_or_else(|e| e.into_inner());
+        let replies: Vec<Value> = pipe.query(&mut conn).map_err(|e| redis_error("Cluster pipeline", e))?;
+        Ok(replies
+            .iter()
+            .map(|reply| RedisValue::from_redis_value(reply, false))
+            .collect())
+    }
+}
@@ -0,0 +1,315 @@
+// Delayed job queue for HarmonyOS NAPI
+
+use napi_derive_ohos::napi;
+use napi_ohos::bindgen_prelude::*;
+use napi_ohos::threadsafe_function::{ThreadsafeFunction, ThreadsafeFunctionCallMode};
+use redis::{Connection, Script};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, LazyLock};
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+use crate::client::RedisClient;
+use crate::error::{redis_error, report_background_error};
+use crate::types::{DelayedJob, DelayedQueueWorkerOptions};
+
+/// Schedule a job: allocate an id, store the payload and add it to the schedule
+///
+/// KEYS[1] = schedule zset, KEYS[2] = payload hash, KEYS[3] = id sequence
+/// ARGV[1] = run-at timestamp in ms (absolute) or delay in ms (relative)
+/// ARGV[2] = "1" if ARGV[1] is relative to the server clock
+/// ARGV[3] = payload
+static SCHEDULE_SCRIPT: LazyLock<Script> = LazyLock::new(|| {
+    Script::new(
+        r#"
+local run_at = tonumber(ARGV[1])
+if ARGV[2] == "1" then
+  local now = redis.call('TIME')
+  run_at = tonumber(now[1]) * 1000 + math.floor(tonumber(now[2]) / 1000) + run_at
+end
+local id = tostring(redis.call('INCR', KEYS[3]))
+redis.call('HSET', KEYS[2], id, ARGV[3])
+redis.call('ZADD', KEYS[1], run_at, id)
+return id
+"#,
+    )
+});
+
+/// Atomically pop up to ARGV[1] jobs whose run-at time has passed (server clock)
+///
+/// KEYS[1] = schedule zset, KEYS[2] = payload hash
+/// Returns a flat array of [id, payload, ...]
+static POP_DUE_SCRIPT: LazyLock<Script> = LazyLock::new(|| {
+    Script::new(
+        r#"
+local now = redis.call('TIME')
+local now_ms = tonumber(now[1]) * 1000 + math.floor(tonumber(now[2]) / 1000)
+local ids = redis.call('ZRANGEBYSCORE', KEYS[1], '-inf', now_ms, 'LIMIT', 0, tonumber(ARGV[1]))
+local result = {}
+for _, id in ipairs(ids) do
+  redis.call('ZREM', KEYS[1], id)
+  local payload = redis.call('HGET', KEYS[2], id)
+  redis.call('HDEL', KEYS[2], id)
+  if payload then
+    table.insert(result, id)
+    table.insert(result, payload)
+  end
+end
+return result
+"#,
+    )
+});
+
+/// Keys used by a queue, sharing a hash tag so they live in the same cluster slot
+#[derive(Clone)]
+struct QueueKeys {
+    schedule: String,
+    jobs: String,
+    sequence: String,
+}
+
+impl QueueKeys {
+    fn new(name: &str) -> Self {
+        QueueKeys {
+            schedule: format!("{{{}}}:schedule", name),
+            jobs: format!("{{{}}}:jobs", name),
+            sequence: format!("{{{}}}:seq", name),
+        }
+    }
+}
+
+fn pop_due(conn: &mut Connection, keys: &QueueKeys, limit: u32) -> redis::RedisResult<Vec<DelayedJob>> {
+    let flat: Vec<(String, String)> = POP_DUE_SCRIPT
+        .key(&keys.schedule)
+        .key(&keys.jobs)
+        .arg(limit)
+        .invoke(conn)?;
+
+    Ok(flat
+        .into_iter()
+        .map(|(id, payload)| DelayedJob { id, payload })
+        .collect())
+}
+
+/// Background mover thread handle
+struct Worker {
+    running: Arc<AtomicBool>,
+    handle: JoinHandle<()>,
+}
+
+/// Delayed job queue backed by a sorted set
+///
+/// Jobs are stored in a sorted set scored by their run-at timestamp (server
+/// clock, in milliseconds) plus a hash holding the payloads. Due jobs are
+/// popped atomically with a Lua script, so every job is delivered to exactly
+/// one consumer even when several devices poll the same queue.
+///
+/// # Example (ArkTS)
+/// ```typescript
+/// const queue = client.getDelayedQueue("reminders");
+///
+/// // Run in 30 seconds
+/// const id = queue.schedule(JSON.stringify({ userId: 42 }), 30000);
+///
+/// // Deliver due jobs to a callback from a background thread
+/// queue.start((job) => {
+///   console.log(`job ${job.id}: ${job.payload}`);
+/// }, { pollIntervalMs: 500 });
+///
+/// // Later
+/// queue.stop();
+/// ```
+#[napi]
+pub struct RedisDelayedQueue {
+    client: RedisClient,
+    conn: Connection,
+    keys: QueueKeys,
+    worker: Option<Worker>,
+}
+
+impl RedisDelayedQueue {
+    pub(crate) fn new(client: RedisClient, conn: Connection, name: &str) -> Self {
+        RedisDelayedQueue {
+            client,
+            conn,
+            keys: QueueKeys::new(name),
+            worker: None,
+        }
+    }
+}
+
+#[napi]
+impl RedisDelayedQueue {
+    /// Schedule a job to run after a delay
+    ///
+    /// # Arguments
+    /// * `payload` - Job payload (e.g., a JSON string)
+    /// * `delay_ms` - Delay in milliseconds, relative to the server clock
+    ///
+    /// # Returns
+    /// The job id
+    #[napi]
+    pub fn schedule(&mut self, payload: String, delay_ms: i64) -> Result<String> {
+        SCHEDULE_SCRIPT
+            .key(&self.keys.schedule)
+            .key(&self.keys.jobs)
+            .key(&self.keys.sequence)
+            .arg(delay_ms.max(0))
+            .arg("1")
+            .arg(payload)
+            .invoke(&mut self.conn)
+            .map_err(|e| redis_error("Delayed queue schedule", e))
+    }
+
+    /// Schedule a job to run at an absolute time
+    ///
+    /// # Arguments
+    /// * `payload` - Job payload
+    /// * `timestamp_ms` - UNIX timestamp in milliseconds
+    ///
+    /// # Returns
+    /// The job id
+    ///
+    /// # Example (ArkTS)
+    /// ```typescript
+    /// const id = queue.scheduleAt("daily-report", Date.now() + 3600000);
+    /// ```
+    #[napi]
+    pub fn schedule_at(&mut self, payload: String, timestamp_ms: i64) -> Result<String> {
+        SCHEDULE_SCRIPT
+            .key(&self.keys.schedule)
+            .key(&self.keys.jobs)
+            .key(&self.keys.sequence)
+            .arg(timestamp_ms)
+            .arg("0")
+            .arg(payload)
+            .invoke(&mut self.conn)
+            .map_err(|e| redis_error("Delayed queue schedule", e))
+    }
+
+    /// Cancel a scheduled job
+    ///
+    /// # Returns
+    /// true if the job was still pending and has been removed
+    #[napi]
+    pub fn cancel(&mut self, id: String) -> Result<bool> {
+        let (removed, _): (i64, i64) = redis::pipe()
+            .atomic()
+            .cmd("ZREM")
+            .arg(&self.keys.schedule)
+            .arg(&id)
+            .cmd("HDEL")
+            .arg(&self.keys.jobs)
+            .arg(&id)
+            .query(&mut self.conn)
+            .map_err(|e| redis_error("Delayed queue cancel", e))?;
+        Ok(removed > 0)
+    }
+
+    /// Number of pending jobs (due or not)
+    #[napi]
+    pub fn size(&mut self) -> Result<i64> {
+        redis::cmd("ZCARD")
+            .arg(&self.keys.schedule)
+            .query(&mut self.conn)
+            .map_err(|e| redis_error("Delayed queue size", e))
+    }
+
+    /// Pop due jobs synchronously
+    ///
+    /// Use this instead of `start()` to drive the queue from your own timer.
+    ///
+    /// # Arguments
+    /// * `limit` - Maximum number of jobs to pop (default: 100)
+    ///
+    /// # Returns
+    /// Array of due jobs, oldest first
+    #[napi]
+    pub fn poll_due(&mut self, limit: Option<u32>) -> Result<Vec<DelayedJob>> {
+        pop_due(&mut self.conn, &self.keys, limit.unwrap_or(100))
+            .map_err(|e| redis_error("Delayed queue poll", e))
+    }
+
+    /// Start a background thread that delivers due jobs to a callback
+    ///
+    /// The thread uses its own connection. Jobs are removed from the queue
+    /// before the callback runs (at-most-once delivery).
+    ///
+    /// # Arguments
+    /// * `callback` - Called with each due DelayedJob on the ArkTS thread
+    /// * `options` - Optional worker options:
+    ///   - `pollIntervalMs`: delay between polls when no job is due (default: 1000)
+    ///   - `batchSize`: maximum jobs popped per poll, at least 1 (default: 100)
+    #[napi]
+    pub fn start(
+        &mut self,
+        callback: ThreadsafeFunction<DelayedJob, Unknown<'static>, DelayedJob, Status, false>,
+        options: Option<DelayedQueueWorkerOptions>,
+    ) -> Result<()> {
+        if self.worker.is_some() {
+            return Err(napi_ohos::Error::from_reason("Delayed queue worker is already running"));
+        }
+
+        let options = options.unwrap_or_default();
+        let interval = Duration::from_millis(options.poll_interval_ms.unwrap_or(1000) as u64);
+        let batch_size = options.batch_size.unwrap_or(100);
+        if batch_size == 0 {
+            return Err(napi_ohos::Error::new(
+                Status::InvalidArg,
+                "Batch size must be at least 1",
+            ));
+        }
+
+        let mut conn = self.client.connect(None).map_err(|e| redis_error("Connect", e))?;
+        let keys = self.keys.clone();
+        let running = Arc::new(AtomicBool::new(true));
+        let flag = running.clone();
+
+        let handle = std::thread::spawn(move || {
+            while flag.load(Ordering::Acquire) {
+                match pop_due(&mut conn, &keys, batch_size) {
+                    Ok(jobs) => {
+                        let full_batch = jobs.len() as u32 >= batch_size;
+                        for job in jobs {
+                            callback.call(job, ThreadsafeFunctionCallMode::NonBlocking);
+                        }
+                        // Keep draining without sleeping while there is a backlog
+                        if full_batch {
+                            continue;
+                        }
+                    }
+                    Err(e) => {
+                        report_background_error("Delayed queue worker", &redis_error("Delayed queue poll", e))
+                    }
+                }
+                std::thread::sleep(interval);
+            }
+        });
+
+        self.worker = Some(Worker { running, handle });
+        Ok(())
+    }
+
+    /// Stop the background worker started with `start()`
+    ///
+    /// Waits for the current poll to finish.
+    #[napi]
+    pub fn stop(&mut self) {
+        if let Some(worker) = self.worker.take() {
+            worker.running.store(false, Ordering::Release);
+            let _ = worker.handle.join();
+        }
+    }
+
+    /// Whether the background worker is running
+    #[napi(getter)]
+    pub fn is_running(&self) -> bool {
+        self.worker.is_some()
+    }
+}
+
+impl Drop for RedisDelayedQueue {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
@@ -0,0 +1,109 @@
+// Address family preference for HarmonyOS NAPI
+//
+// Some network stacks resolve dual-stack hostnames to IPv6 addresses that
+// are not reachable. A client can be limited to one address family, or be
+// given an address the app resolved itself.
+//
+// Async connections resolve through an AsyncDNSResolver, so the hostname is
+// kept for TLS. Sync connections over plain TCP are opened to the chosen
+// address directly; sync TLS connections still use the system resolver,
+// since their certificate is checked against the hostname.
+
+use napi_ohos::bindgen_prelude::*;
+use napi_ohos::tokio::net::lookup_host;
+use redis::io::AsyncDNSResolver;
+use redis::{Client, Connection, ConnectionAddr, ConnectionInfo, RedisFuture, RedisResult};
+use std::net::{IpAddr, SocketAddr, ToSocketAddrs};
+use std::time::Duration;
+
+use crate::types::{IpFamily, RedisClientConfig};
+
+/// Which addresses a client connects to
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct AddressPreference {
+    family: IpFamily,
+    /// Address used instead of resolving the host
+    address: Option<IpAddr>,
+}
+
+impl AddressPreference {
+    /// The preference set by `ip_family` and `resolved_address`, if any
+    pub(crate) fn from_config(config: &RedisClientConfig) -> Result<Option<Self>> {
+        let address = match &config.resolved_address {
+            Some(address) => Some(address.parse::<IpAddr>().map_err(|_| {
+                napi_ohos::Error::new(Status::InvalidArg, format!("Invalid resolved address: {}", address))
+            })?),
+            None => None,
+        };
+        let family = config.ip_family.unwrap_or(IpFamily::Any);
+        if address.is_none() && family == IpFamily::Any {
+            return Ok(None);
+        }
+        Ok(Some(AddressPreference { family, address }))
+    }
+
+    /// Keep the resolved addresses of the preferred family, in resolver order
+    fn select(&self, addrs: impl Iterator<Item = SocketAddr>, host: &str) -> std::io::Result<Vec<SocketAddr>> {
+        let addrs: Vec<SocketAddr> = addrs
+            .filter(|addr| match self.family {
+                IpFamily::Any => true,
+                IpFamily::Ipv4 => addr.is_ipv4(),
+                IpFamily::Ipv6 => addr.is_ipv6(),
+            })
+            .collect();
+        if addrs.is_empty() {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                format!("{} has no {} address", host, self.family.name()),
+            ));
+        }
+        Ok(addrs)
+    }
+
+    /// Open a sync connection to the preferred addresses of `info`
+    ///
+    /// Each address is tried in turn; the last failure is returned.
+    pub(crate) fn connect(&self, info: &ConnectionInfo, timeout: Option<Duration>) -> RedisResult<Connection> {
+        let ConnectionAddr::Tcp(host, port) = info.addr() else {
+            return open(info.clone(), timeout);
+        };
+        let addrs = match self.address {
+            Some(ip) => vec![SocketAddr::new(ip, *port)],
+            None => self.select((host.as_str(), *port).to_socket_addrs()?, host)?,
+        };
+
+        let mut last_error = None;
+        for addr in addrs {
+            let info = info.clone().set_addr(ConnectionAddr::Tcp(addr.ip().to_string(), *port));
+            match open(info, timeout) {
+                Ok(conn) => return Ok(conn),
+                Err(e) => last_error = Some(e),
+            }
+        }
+        Err(last_error.expect("at least one address was selected"))
+    }
+}
+
+fn open(info: ConnectionInfo, timeout: Option<Duration>) -> RedisResult<Connection> {
+    let client = Client::open(info)?;
+    match timeout {
+        Some(timeout) => client.get_connection_with_timeout(timeout),
+        None => client.get_connection(),
+    }
+}
+
+impl AsyncDNSResolver for AddressPreference {
+    fn resolve<'a, 'b: 'a>(
+        &'a self,
+        host: &'b str,
+        port: u16,
+    ) -> RedisFuture<'a, Box<dyn Iterator<Item = SocketAddr> + Send + 'a>> {
+        Box::pin(async move {
+            let addrs = match self.address {
+                Some(ip) => vec![SocketAddr::new(ip, port)],
+                None => self.select(lookup_host((host, port)).await?, host)?,
+            };
+            Ok(Box::new(addrs.into_iter()) as Box<dyn Iterator<Item = SocketAddr> + Send>)
+        })
+    }
+}
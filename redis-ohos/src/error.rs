@@ -0,0 +1,306 @@
+// Error classification for HarmonyOS NAPI
+//
+// NAPI errors only carry a message, so failures coming from redis-rs are
+// tagged with their kind (and server error code, if any) at the start of the
+// message: "[Moved:MOVED] GET failed: ...". `parseRedisError()` turns such a
+// message back into a RedisErrorInfo.
+//
+// Errors on background threads have no caller to throw to; they are logged
+// and passed to the handler registered with `setGlobalErrorHandler()`.
+
+use napi_derive_ohos::napi;
+use napi_ohos::bindgen_prelude::*;
+use napi_ohos::threadsafe_function::{ThreadsafeFunction, ThreadsafeFunctionCallMode};
+use redis::{ErrorKind, RedisError, ServerErrorKind};
+use std::sync::Mutex;
+
+/// Category of a failed Redis operation
+#[napi(string_enum)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RedisErrorKind {
+    /// Network or socket failure
+    IoError,
+    /// The operation did not finish in time
+    Timeout,
+    /// AUTH failed or credentials were rejected
+    AuthenticationFailed,
+    /// The reply could not be converted to the expected type
+    TypeError,
+    /// WRONGTYPE: the key holds a different data type
+    WrongType,
+    /// The server sent a reply that could not be parsed
+    ParseError,
+    /// Invalid connection parameters
+    InvalidClientConfig,
+    /// Error detected on the client before sending the command
+    ClientError,
+    /// Generic ERR reply
+    ResponseError,
+    /// EXECABORT: a transaction was discarded
+    ExecAbort,
+    /// LOADING: the server is loading its dataset
+    BusyLoading,
+    /// BUSY: a script or function is running
+    Busy,
+    /// NOSCRIPT: unknown script SHA
+    NoScript,
+    /// MOVED cluster redirection
+    Moved,
+    /// ASK cluster redirection
+    Ask,
+    /// TRYAGAIN: retry later (cluster resharding)
+    TryAgain,
+    /// CLUSTERDOWN: the cluster is unavailable
+    ClusterDown,
+    /// CROSSSLOT: keys hash to different slots
+    CrossSlot,
+    /// MASTERDOWN: the master link is down
+    MasterDown,
+    /// READONLY: write sent to a replica
+    ReadOnly,
+    /// NOTBUSY: no script to kill
+    NotBusy,
+    /// NOSUB: not subscribed
+    NoSub,
+    /// NOPERM: denied by ACL
+    NoPerm,
+    /// Any other error code sent by the server
+    ExtensionError,
+    /// The operation was cancelled with a CancellationToken
+    Cancelled,
+    /// Error that doesn't fit any other kind
+    Other,
+}
+
+impl RedisErrorKind {
+    /// Classify a redis-rs error
+    pub fn from_redis_error(e: &RedisError) -> Self {
+        if e.is_timeout() {
+            return RedisErrorKind::Timeout;
+        }
+        match e.kind() {
+            ErrorKind::Io => RedisErrorKind::IoError,
+            ErrorKind::AuthenticationFailed => RedisErrorKind::AuthenticationFailed,
+            ErrorKind::UnexpectedReturnType => RedisErrorKind::TypeError,
+            ErrorKind::Parse => RedisErrorKind::ParseError,
+            ErrorKind::InvalidClientConfig => RedisErrorKind::InvalidClientConfig,
+            ErrorKind::Client => RedisErrorKind::ClientError,
+            ErrorKind::Server(kind) => match kind {
+                ServerErrorKind::ResponseError => RedisErrorKind::ResponseError,
+                ServerErrorKind::ExecAbort => RedisErrorKind::ExecAbort,
+                ServerErrorKind::BusyLoading => RedisErrorKind::BusyLoading,
+                ServerErrorKind::NoScript => RedisErrorKind::NoScript,
+                ServerErrorKind::Moved => RedisErrorKind::Moved,
+                ServerErrorKind::Ask => RedisErrorKind::Ask,
+                ServerErrorKind::TryAgain => RedisErrorKind::TryAgain,
+                ServerErrorKind::ClusterDown => RedisErrorKind::ClusterDown,
+                ServerErrorKind::CrossSlot => RedisErrorKind::CrossSlot,
+                ServerErrorKind::MasterDown => RedisErrorKind::MasterDown,
+                ServerErrorKind::ReadOnly => RedisErrorKind::ReadOnly,
+                ServerErrorKind::NotBusy => RedisErrorKind::NotBusy,
+                ServerErrorKind::NoSub => RedisErrorKind::NoSub,
+                ServerErrorKind::NoPerm => RedisErrorKind::NoPerm,
+                _ => RedisErrorKind::ExtensionError,
+            },
+            ErrorKind::Extension => match e.code() {
+                Some("WRONGTYPE") => RedisErrorKind::WrongType,
+                Some("BUSY") => RedisErrorKind::Busy,
+                _ => RedisErrorKind::ExtensionError,
+            },
+            _ => RedisErrorKind::Other,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            RedisErrorKind::IoError => "IoError",
+            RedisErrorKind::Timeout => "Timeout",
+            RedisErrorKind::AuthenticationFailed => "AuthenticationFailed",
+            RedisErrorKind::TypeError => "TypeError",
+            RedisErrorKind::WrongType => "WrongType",
+            RedisErrorKind::ParseError => "ParseError",
+            RedisErrorKind::InvalidClientConfig => "InvalidClientConfig",
+            RedisErrorKind::ClientError => "ClientError",
+            RedisErrorKind::ResponseError => "ResponseError",
+            RedisErrorKind::ExecAbort => "ExecAbort",
+            RedisErrorKind::BusyLoading => "BusyLoading",
+            RedisErrorKind::Busy => "Busy",
+            RedisErrorKind::NoScript => "NoScript",
+            RedisErrorKind::Moved => "Moved",
+            RedisErrorKind::Ask => "Ask",
+            RedisErrorKind::TryAgain => "TryAgain",
+            RedisErrorKind::ClusterDown => "ClusterDown",
+            RedisErrorKind::CrossSlot => "CrossSlot",
+            RedisErrorKind::MasterDown => "MasterDown",
+            RedisErrorKind::ReadOnly => "ReadOnly",
+            RedisErrorKind::NotBusy => "NotBusy",
+            RedisErrorKind::NoSub => "NoSub",
+            RedisErrorKind::NoPerm => "NoPerm",
+            RedisErrorKind::ExtensionError => "ExtensionError",
+            RedisErrorKind::Cancelled => "Cancelled",
+            RedisErrorKind::Other => "Other",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        const ALL: [RedisErrorKind; 26] = [
+            RedisErrorKind::IoError,
+            RedisErrorKind::Timeout,
+            RedisErrorKind::AuthenticationFailed,
+            RedisErrorKind::TypeError,
+            RedisErrorKind::WrongType,
+            RedisErrorKind::ParseError,
+            RedisErrorKind::InvalidClientConfig,
+            RedisErrorKind::ClientError,
+            RedisErrorKind::ResponseError,
+            RedisErrorKind::ExecAbort,
+            RedisErrorKind::BusyLoading,
+            RedisErrorKind::Busy,
+            RedisErrorKind::NoScript,
+            RedisErrorKind::Moved,
+            RedisErrorKind::Ask,
+            RedisErrorKind::TryAgain,
+            RedisErrorKind::ClusterDown,
+            RedisErrorKind::CrossSlot,
+            RedisErrorKind::MasterDown,
+            RedisErrorKind::ReadOnly,
+            RedisErrorKind::NotBusy,
+            RedisErrorKind::NoSub,
+            RedisErrorKind::NoPerm,
+            RedisErrorKind::ExtensionError,
+            RedisErrorKind::Cancelled,
+            RedisErrorKind::Other,
+        ];
+        ALL.into_iter().find(|kind| kind.name() == name)
+    }
+}
+
+/// A Redis error message split into its parts
+#[napi(object)]
+#[derive(Debug, Clone)]
+pub struct RedisErrorInfo {
+    /// Error category
+    pub kind: RedisErrorKind,
+
+    /// Server error code (e.g. "MOVED", "WRONGTYPE"), if the server sent one
+    pub code: Option<String>,
+
+    /// The message without the kind tag
+    pub message: String,
+}
+
+/// Build a NAPI error for a failed redis-rs operation
+///
+/// The message reads "[Kind:CODE] <context> failed: <error>".
+pub(crate) fn redis_error(context: &str, e: RedisError) -> napi_ohos::Error {
+    let kind = RedisErrorKind::from_redis_error(&e);
+    let tag = match e.code() {
+        Some(code) => format!("{}:{}", kind.name(), code),
+        None => kind.name().to_string(),
+    };
+    napi_ohos::Error::from_reason(format!("[{}] {} failed: {}", tag, context, e))
+}
+
+/// Build the NAPI error for an operation cancelled with a CancellationToken
+pub(crate) fn cancelled_error(context: &str) -> napi_ohos::Error {
+    napi_ohos::Error::from_reason(format!("[Cancelled] {} cancelled", context))
+}
+
+/// Parse the kind tag of an error thrown by this module
+///
+/// # Arguments
+/// * `message` - The `message` of a caught error
+///
+/// # Returns
+/// The error details, or null if the error didn't come from Redis
+///
+/// # Example (ArkTS)
+/// ```typescript
+/// try {
+///   conn.lpush("profile:42", "x");
+/// } catch (e) {
+///   const info = parseRedisError(e.message);
+///   if (info?.kind === RedisErrorKind.WrongType) {
+///     conn.del("profile:42");
+///   }
+/// }
+/// ```
+#[napi]
+pub fn parse_redis_error(message: String) -> Option<RedisErrorInfo> {
+    let rest = message.strip_prefix('[')?;
+    let (tag, rest) = rest.split_once("] ")?;
+    let (name, code) = match tag.split_once(':') {
+        Some((name, code)) => (name, Some(code.to_string())),
+        None => (tag, None),
+    };
+
+    Some(RedisErrorInfo {
+        kind: RedisErrorKind::from_name(name)?,
+        code,
+        message: rest.to_string(),
+    })
+}
+
+/// An error that happened on a background thread
+#[napi(object)]
+#[derive(Debug, Clone)]
+pub struct BackgroundError {
+    /// Component that failed (e.g. "Delayed queue worker", "RPC server")
+    pub source: String,
+
+    /// Error category, if the error came from Redis
+    pub kind: Option<RedisErrorKind>,
+
+    /// Server error code, if the server sent one
+    pub code: Option<String>,
+
+    /// Error message
+    pub message: String,
+}
+
+type ErrorHandler = ThreadsafeFunction<BackgroundError, Unknown<'static>, BackgroundError, Status, false>;
+
+static GLOBAL_ERROR_HANDLER: Mutex<Option<ErrorHandler>> = Mutex::new(None);
+
+/// Receive errors raised on background threads
+///
+/// Workers, watchers and servers running in the background cannot throw to
+/// ArkTS; their failures are logged to hilog and, once a handler is set,
+/// also delivered to it on the ArkTS thread.
+///
+/// # Arguments
+/// * `callback` - Called with each BackgroundError, or null to remove the handler
+///
+/// # Example (ArkTS)
+/// ```typescript
+/// setGlobalErrorHandler((err) => {
+///   console.error(`${err.source}: ${err.message}`);
+///   if (err.kind === RedisErrorKind.IoError) {
+///     scheduleReconnect();
+///   }
+/// });
+/// ```
+#[napi]
+pub fn set_global_error_handler(callback: Option<ErrorHandler>) {
+    let mut handler = GLOBAL_ERROR_HANDLER.lock().unwrap_or_else(|e| e.into_inner());
+    *handler = callback;
+}
+
+/// Log an error from a background thread and pass it to the global handler
+pub(crate) fn report_background_error(source: &str, error: &napi_ohos::Error) {
+    tracing::error!("{}: {}", source, error.reason);
+
+    let handler = GLOBAL_ERROR_HANDLER.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some(callback) = handler.as_ref() {
+        let info = parse_redis_error(error.reason.clone());
+        callback.call(
+            BackgroundError {
+                source: source.to_string(),
+                kind: info.as_ref().map(|info| info.kind),
+                code: info.and_then(|info| info.code),
+                message: error.reason.clone(),
+            },
+            ThreadsafeFunctionCallMode::NonBlocking,
+        );
+    }
+}
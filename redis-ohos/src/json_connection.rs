@@ -5,6 +5,8 @@ use napi_ohos::{Error, Result, Status};
 use redis::{Connection, JsonCommands};
 use serde_json::Value as JsonValue;
 
+use crate::error::redis_error;
+
 /// Redis JSON Connection for HarmonyOS
 ///
 /// This class provides JSON-specific Redis commands for working with RedisJSON module.
@@ -43,7 +45,7 @@ impl RedisJsonConnection {
             .map_err(|e| Error::new(Status::InvalidArg, format!("Invalid JSON: {}", e)))?;
 
         JsonCommands::json_set(&mut self.inner, key, path, &value)
-            .map_err(|e| napi_ohos::Error::from_reason(format!("JSON.SET failed: {}", e)))
+            .map_err(|e| redis_error("JSON.SET", e))
     }
 
     /// JSON.GET command - Get JSON value at path
@@ -63,7 +65,7 @@ impl RedisJsonConnection {
     #[napi]
     pub fn json_get(&mut self, key: String, path: String) -> Result<String> {
         JsonCommands::json_get(&mut self.inner, key, path)
-            .map_err(|e| napi_ohos::Error::from_reason(format!("JSON.GET failed: {}", e)))
+            .map_err(|e| redis_error("JSON.GET", e))
     }
 
     /// JSON.DEL command - Delete JSON value at path
@@ -75,9 +77,9 @@ impl RedisJsonConnection {
     /// # Returns
     /// Number of paths deleted
     #[napi]
-    pub fn json_del(&mut self, key: String, path: String) -> Result<i32> {
+    pub fn json_del(&mut self, key: String, path: String) -> Result<i64> {
         JsonCommands::json_del(&mut self.inner, key, path)
-            .map_err(|e| napi_ohos::Error::from_reason(format!("JSON.DEL failed: {}", e)))
+            .map_err(|e| redis_error("JSON.DEL", e))
     }
 
     /// JSON.TYPE command - Get the type of JSON value at path
@@ -91,7 +93,7 @@ impl RedisJsonConnection {
     #[napi]
     pub fn json_type(&mut self, key: String, path: String) -> Result<String> {
         JsonCommands::json_type(&mut self.inner, key, path)
-            .map_err(|e| napi_ohos::Error::from_reason(format!("JSON.TYPE failed: {}", e)))
+            .map_err(|e| redis_error("JSON.TYPE", e))
     }
 
     // ==================== JSON Array Commands ====================
@@ -106,12 +108,12 @@ impl RedisJsonConnection {
     /// # Returns
     /// New length of the array
     #[napi]
-    pub fn json_arr_append(&mut self, key: String, path: String, json: String) -> Result<i32> {
+    pub fn json_arr_append(&mut self, key: String, path: String, json: String) -> Result<i64> {
         let value: JsonValue = serde_json::from_str(&json)
             .map_err(|e| Error::new(Status::InvalidArg, format!("Invalid JSON: {}", e)))?;
 
         JsonCommands::json_arr_append(&mut self.inner, key, path, &value)
-            .map_err(|e| napi_ohos::Error::from_reason(format!("JSON.ARRAPPEND failed: {}", e)))
+            .map_err(|e| redis_error("JSON.ARRAPPEND", e))
     }
 
     /// JSON.ARRINDEX command - Find index of JSON value in array
@@ -124,12 +126,12 @@ impl RedisJsonConnection {
     /// # Returns
     /// Index of first occurrence, or -1 if not found
     #[napi]
-    pub fn json_arr_index(&mut self, key: String, path: String, json: String) -> Result<i32> {
+    pub fn json_arr_index(&mut self, key: String, path: String, json: String) -> Result<i64> {
         let value: JsonValue = serde_json::from_str(&json)
             .map_err(|e| Error::new(Status::InvalidArg, format!("Invalid JSON: {}", e)))?;
 
         JsonCommands::json_arr_index(&mut self.inner, key, path, &value)
-            .map_err(|e| napi_ohos::Error::from_reason(format!("JSON.ARRINDEX failed: {}", e)))
+            .map_err(|e| redis_error("JSON.ARRINDEX", e))
     }
 
     /// JSON.ARRINSERT command - Insert values into JSON array
@@ -149,12 +151,12 @@ impl RedisJsonConnection {
         path: String,
         index: i32,
         json: String,
-    ) -> Result<i32> {
+    ) -> Result<i64> {
         let value: JsonValue = serde_json::from_str(&json)
             .map_err(|e| Error::new(Status::InvalidArg, format!("Invalid JSON: {}", e)))?;
 
         JsonCommands::json_arr_insert(&mut self.inner, key, path, index as i64, &value)
-            .map_err(|e| napi_ohos::Error::from_reason(format!("JSON.ARRINSERT failed: {}", e)))
+            .map_err(|e| redis_error("JSON.ARRINSERT", e))
     }
 
     /// JSON.ARRLEN command - Get length of JSON array
@@ -166,9 +168,9 @@ impl RedisJsonConnection {
     /// # Returns
     /// Length of the array
     #[napi]
-    pub fn json_arr_len(&mut self, key: String, path: String) -> Result<i32> {
+    pub fn json_arr_len(&mut self, key: String, path: String) -> Result<i64> {
         JsonCommands::json_arr_len(&mut self.inner, key, path)
-            .map_err(|e| napi_ohos::Error::from_reason(format!("JSON.ARRLEN failed: {}", e)))
+            .map_err(|e| redis_error("JSON.ARRLEN", e))
     }
 
     /// JSON.ARRPOP command - Remove and return element from array
@@ -189,7 +191,7 @@ impl RedisJsonConnection {
     ) -> Result<String> {
         let idx = index.unwrap_or(-1) as i64;
         JsonCommands::json_arr_pop(&mut self.inner, key, path, idx)
-            .map_err(|e| napi_ohos::Error::from_reason(format!("JSON.ARRPOP failed: {}", e)))
+            .map_err(|e| redis_error("JSON.ARRPOP", e))
     }
 
     /// JSON.ARRTRIM command - Trim array to specified range
@@ -209,9 +211,9 @@ impl RedisJsonConnection {
         path: String,
         start: i32,
         stop: i32,
-    ) -> Result<i32> {
+    ) -> Result<i64> {
         JsonCommands::json_arr_trim(&mut self.inner, key, path, start as i64, stop as i64)
-            .map_err(|e| napi_ohos::Error::from_reason(format!("JSON.ARRTRIM failed: {}", e)))
+            .map_err(|e| redis_error("JSON.ARRTRIM", e))
     }
 
     // ==================== JSON Object Commands ====================
@@ -227,7 +229,7 @@ impl RedisJsonConnection {
     #[napi]
     pub fn json_obj_keys(&mut self, key: String, path: String) -> Result<Vec<String>> {
         JsonCommands::json_obj_keys(&mut self.inner, key, path)
-            .map_err(|e| napi_ohos::Error::from_reason(format!("JSON.OBJKEYS failed: {}", e)))
+            .map_err(|e| redis_error("JSON.OBJKEYS", e))
     }
 
     /// JSON.OBJLEN command - Get number of keys in JSON object
@@ -239,9 +241,9 @@ impl RedisJsonConnection {
     /// # Returns
     /// Number of keys in the object
     #[napi]
-    pub fn json_obj_len(&mut self, key: String, path: String) -> Result<i32> {
+    pub fn json_obj_len(&mut self, key: String, path: String) -> Result<i64> {
         JsonCommands::json_obj_len(&mut self.inner, key, path)
-            .map_err(|e| napi_ohos::Error::from_reason(format!("JSON.OBJLEN failed: {}", e)))
+            .map_err(|e| redis_error("JSON.OBJLEN", e))
     }
 
     // ==================== JSON String Commands ====================
@@ -256,7 +258,7 @@ impl RedisJsonConnection {
     /// # Returns
     /// New length of the string
     #[napi]
-    pub fn json_str_append(&mut self, key: String, path: String, value: String) -> Result<i32> {
+    pub fn json_str_append(&mut self, key: String, path: String, value: String) -> Result<i64> {
         // JSON-encode the string value
         let json_str = serde_json::to_string(&value).map_err(|e| {
             Error::new(
@@ -266,7 +268,7 @@ impl RedisJsonConnection {
         })?;
 
         JsonCommands::json_str_append(&mut self.inner, key, path, &json_str)
-            .map_err(|e| napi_ohos::Error::from_reason(format!("JSON.STRAPPEND failed: {}", e)))
+            .map_err(|e| redis_error("JSON.STRAPPEND", e))
     }
 
     /// JSON.STRLEN command - Get length of JSON string value
@@ -278,9 +280,9 @@ impl RedisJsonConnection {
     /// # Returns
     /// Length of the string
     #[napi]
-    pub fn json_str_len(&mut self, key: String, path: String) -> Result<i32> {
+    pub fn json_str_len(&mut self, key: String, path: String) -> Result<i64> {
         JsonCommands::json_str_len(&mut self.inner, key, path)
-            .map_err(|e| napi_ohos::Error::from_reason(format!("JSON.STRLEN failed: {}", e)))
+            .map_err(|e| redis_error("JSON.STRLEN", e))
     }
 
     // ==================== JSON Number Commands ====================
@@ -297,6 +299,6 @@ impl RedisJsonConnection {
     #[napi]
     pub fn json_num_incr_by(&mut self, key: String, path: String, value: i64) -> Result<String> {
         JsonCommands::json_num_incr_by(&mut self.inner, key, path, value)
-            .map_err(|e| napi_ohos::Error::from_reason(format!("JSON.NUMINCRBY failed: {}", e)))
+            .map_err(|e| redis_error("JSON.NUMINCRBY", e))
     }
 }
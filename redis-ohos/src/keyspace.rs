@@ -0,0 +1,209 @@
+// Keyspace notification watcher for HarmonyOS NAPI
+//
+// Subscribes to `__keyspace@<db>__:<pattern>` so the server filters keys by
+// pattern, and filters event names on the client side.
+
+use napi_derive_ohos::napi;
+use napi_ohos::bindgen_prelude::*;
+use napi_ohos::threadsafe_function::{ThreadsafeFunction, ThreadsafeFunctionCallMode};
+use redis::Connection;
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+use crate::client::RedisClient;
+use crate::error::{redis_error, report_background_error};
+use crate::types::{KeyEvent, KeyEventWatchOptions};
+
+/// Event classes included in the `A` alias of notify-keyspace-events
+const ALL_CLASSES: &str = "g$lshzxet";
+
+/// notify-keyspace-events class flag that enables an event
+fn event_class(event: &str) -> char {
+    match event {
+        "expired" => 'x',
+        "evicted" => 'e',
+        "new" => 'n',
+        "del" | "expire" | "persist" | "rename_from" | "rename_to" | "copy_to" | "move_from" | "move_to"
+        | "restore" | "sortstore" => 'g',
+        "set" | "setrange" | "incrby" | "incrbyfloat" | "append" => '$',
+        e if e.starts_with('h') => 'h',
+        e if e.starts_with('z') => 'z',
+        e if e.starts_with('x') => 't',
+        e if e.starts_with('s') => 's',
+        e if e.starts_with('l') || e.starts_with('r') => 'l',
+        // Unknown event: enable every class rather than miss it
+        _ => 'A',
+    }
+}
+
+/// Flags to set so `current` also delivers keyspace events of the given classes
+///
+/// Returns None if `current` already covers them.
+fn merge_flags(current: &str, classes: &HashSet<char>) -> Option<String> {
+    let mut flags = current.to_string();
+    let covered = |flags: &str, class: char| {
+        flags.contains(class) || (flags.contains('A') && ALL_CLASSES.contains(class))
+    };
+
+    if !flags.contains('K') {
+        flags.push('K');
+    }
+    for &class in classes {
+        if !covered(&flags, class) {
+            flags.push(class);
+        }
+    }
+    (flags != current).then_some(flags)
+}
+
+/// Enable the keyspace notifications needed for `events`
+fn configure_notifications(conn: &mut Connection, events: &HashSet<String>) -> Result<()> {
+    let classes: HashSet<char> = if events.is_empty() {
+        HashSet::from(['A'])
+    } else {
+        events.iter().map(|event| event_class(event)).collect()
+    };
+
+    let config: HashMap<String, String> = redis::cmd("CONFIG")
+        .arg("GET")
+        .arg("notify-keyspace-events")
+        .query(conn)
+        .map_err(|e| redis_error("CONFIG GET notify-keyspace-events", e))?;
+    let current = config.get("notify-keyspace-events").map_or("", String::as_str);
+
+    if let Some(flags) = merge_flags(current, &classes) {
+        redis::cmd("CONFIG")
+            .arg("SET")
+            .arg("notify-keyspace-events")
+            .arg(flags)
+            .query::<()>(conn)
+            .map_err(|e| redis_error("CONFIG SET notify-keyspace-events", e))?;
+    }
+    Ok(())
+}
+
+/// Handle of a keyspace event subscription
+///
+/// Created with `RedisClient.watchKeyEvents()`. Events stop when `stop()`
+/// is called or the watcher is garbage collected.
+#[napi]
+pub struct KeyEventWatcher {
+    running: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl KeyEventWatcher {
+    /// Subscribe and start the delivery thread
+    pub(crate) fn start(
+        client: &RedisClient,
+        db: i64,
+        pattern: String,
+        events: Vec<String>,
+        callback: ThreadsafeFunction<KeyEvent, Unknown<'static>, KeyEvent, Status, false>,
+        options: Option<KeyEventWatchOptions>,
+    ) -> Result<Self> {
+        let options = options.unwrap_or_default();
+        let events: HashSet<String> = events.into_iter().map(|event| event.to_lowercase()).collect();
+
+        if options.configure_server.unwrap_or(true) {
+            let mut conn = client.connect(None).map_err(|e| redis_error("Connect", e))?;
+            configure_notifications(&mut conn, &events)?;
+        }
+
+        let mut conn = client.connect(None).map_err(|e| redis_error("Connect", e))?;
+        let prefix = format!("__keyspace@{}__:", db);
+        let channel_pattern = format!("{}{}", prefix, pattern);
+
+        let running = Arc::new(AtomicBool::new(true));
+        let flag = running.clone();
+        // The subscription lives inside the thread (dropping PubSub unsubscribes);
+        // its outcome is reported back before start() returns
+        let (ready_tx, ready_rx) = std::sync::mpsc::channel();
+
+        let handle = std::thread::spawn(move || {
+            let mut pubsub = conn.as_pubsub();
+            let subscribed = pubsub
+                .psubscribe(&channel_pattern)
+                .and_then(|_| pubsub.set_read_timeout(Some(Duration::from_millis(500))));
+            let failed = subscribed.is_err();
+            let _ = ready_tx.send(subscribed);
+            if failed {
+                return;
+            }
+
+            while flag.load(Ordering::Acquire) {
+                let msg = match pubsub.get_message() {
+                    Ok(msg) => msg,
+                    Err(e) if e.is_timeout() => continue,
+                    Err(e) => {
+                        report_background_error("Key event watcher", &redis_error("Key event watch", e));
+                        break;
+                    }
+                };
+
+                let event = String::from_utf8_lossy(msg.get_payload_bytes()).into_owned();
+                if !events.is_empty() && !events.contains(&event) {
+                    continue;
+                }
+                let Some(key) = msg.get_channel_name().strip_prefix(prefix.as_str()) else {
+                    continue;
+                };
+
+                callback.call(
+                    KeyEvent {
+                        key: key.to_string(),
+                        event,
+                        db,
+                    },
+                    ThreadsafeFunctionCallMode::NonBlocking,
+                );
+            }
+        });
+
+        match ready_rx.recv() {
+            Ok(Ok(())) => {}
+            Ok(Err(e)) => {
+                let _ = handle.join();
+                return Err(redis_error("PSUBSCRIBE", e));
+            }
+            Err(_) => {
+                let _ = handle.join();
+                return Err(napi_ohos::Error::from_reason("Key event watcher failed to start"));
+            }
+        }
+
+        Ok(KeyEventWatcher {
+            running,
+            handle: Some(handle),
+        })
+    }
+}
+
+#[napi]
+impl KeyEventWatcher {
+    /// Stop delivering events
+    ///
+    /// Waits for the subscriber thread to exit.
+    #[napi]
+    pub fn stop(&mut self) {
+        if let Some(handle) = self.handle.take() {
+            self.running.store(false, Ordering::Release);
+            let _ = handle.join();
+        }
+    }
+
+    /// Whether events are still being delivered
+    #[napi(getter)]
+    pub fn is_running(&self) -> bool {
+        self.handle.as_ref().is_some_and(|handle| !handle.is_finished())
+    }
+}
+
+impl Drop for KeyEventWatcher {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
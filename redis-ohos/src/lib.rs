@@ -9,17 +9,85 @@
 pub use crate::native_log::*;
 
 // Modules
+mod async_connection;
+mod async_json_connection;
+mod async_pipeline;
+mod backup;
+mod cancel;
 mod client;
+mod cluster;
+mod cluster_pipeline;
 mod connection;
+mod delayed_queue;
+mod dns;
+mod error;
 mod json_connection;
+mod keyspace;
+mod metrics;
 mod native_log;
+mod pipeline;
+mod presence;
+mod rpc;
+mod runtime;
+mod script;
+mod semaphore;
+mod sentinel;
+mod stream_consumer;
 mod types;
 
 // Re-export main types
+pub use async_connection::AsyncRedisConnection;
+pub use async_json_connection::AsyncRedisJsonConnection;
+pub use async_pipeline::AsyncRedisPipeline;
+pub use cancel::CancellationToken;
 pub use client::RedisClient;
+pub use cluster::{RedisClusterClient, RedisClusterConnection};
+pub use cluster_pipeline::RedisClusterPipeline;
 pub use connection::RedisConnection;
+pub use delayed_queue::RedisDelayedQueue;
+pub use error::{parse_redis_error, set_global_error_handler, BackgroundError, RedisErrorInfo, RedisErrorKind};
 pub use json_connection::RedisJsonConnection;
+pub use keyspace::KeyEventWatcher;
+pub use pipeline::RedisPipeline;
+pub use presence::PresenceTracker;
+pub use rpc::RedisRpcServer;
+pub use runtime::init_runtime;
+pub use script::RedisScript;
+pub use semaphore::RedisSemaphore;
+pub use sentinel::{RedisSentinelClient, SentinelFailoverWatcher};
+pub use stream_consumer::RedisStreamConsumer;
 pub use types::{
-    RedisValueType, RedisExpireOption, RedisExpireResult,
-    RedisClientConfig, DatabaseStats, RedisInfo
+    RedisValueType, RedisValueKind, RedisValue, RedisValueEntry, RedisExpireOption, RedisExpireResult,
+    RedisClientConfig, TransientRetryConfig, ConnectionRetryConfig, DatabaseStats, RedisInfo,
+    MemoryAnalysisOptions, NamespaceMemoryStats,
+    HotKeySampleMode, HotKeySampleOptions, HotKeyInfo,
+    KeyExportFormat, KeyExportOptions, KeyImportOptions, KeyImportResult,
+    MigrationConflictPolicy, MigrationOptions, MigrationReport,
+    DatasetExportOptions,
+    DelayedJob, DelayedQueueWorkerOptions,
+    KeyBrowseItem, KeyBrowsePage, InspectKeyOptions, KeyInspection,
+    KeyTreeOptions, KeyTreeNode,
+    RenameOptions, RenameReport, CopyKeysOptions, CopyKeysReport,
+    DiffMode, DiffOptions, DatabaseDiff,
+    RespImportReport,
+    PresenceEventKind, PresenceEvent,
+    OnceResult,
+    EntityFieldType, EntityField,
+    ScoredMember,
+    HexpireBatchEntry, HexpireFieldResult,
+    ConnectionMetrics, LatencyBucket,
+    PanicInfo,
+    ListDirection, BlockingPopResult, BlockingZsetPopResult,
+    KeyEvent, KeyEventWatchOptions,
+    PushMessage,
+    XAddOptions, StreamEntry, StreamReadResult,
+    StreamPendingSummary, StreamPendingConsumer, StreamPendingEntry, StreamAutoClaimResult,
+    StreamInfo, StreamGroupInfo, StreamConsumerInfo, XTrimOptions,
+    StreamConsumerOptions, RedisClusterConfig, ClusterReadFrom,
+    ClusterNode, ClusterSlotRange, ClusterSlotNode, ClusterSlotInfo,
+    ClusterSlotState, ClusterFailoverMode, RedisSentinelConfig,
+    SentinelFailoverEvent, IpFamily,
+    ValueEncoding, EncodedValue,
+    SetOptions, SetResult,
+    LcsOptions, LcsMatch, LcsResult, TtlEntry
 };
\ No newline at end of file
@@ -0,0 +1,723 @@
+// Connection metrics for HarmonyOS NAPI
+//
+// MeteredConnection wraps a redis::Connection and records every request that
+// goes through it. Recording is a handful of integer additions per round trip,
+// so it is always on. It also retries single commands rejected with a
+// transient server error when a TransientRetryConfig is set, and resends
+// single commands after reconnecting when a ConnectionRetryConfig is set.
+//
+// Optionally, MOVED and ASK replies are followed to the node they point to,
+// so a plain connection can be used against a Redis Cluster, and a broken or
+// demoted (READONLY) server is replaced through a reconnect hook, e.g. by
+// asking Sentinel for the current master.
+//
+// The socket and its counters sit behind a mutex so that clones of a
+// MeteredConnection can be used from several threads; the lock is held for
+// one round trip at a time.
+
+use redis::{
+    Cmd, Connection, ConnectionAddr, ConnectionInfo, ConnectionLike, ErrorKind, RedisError, RedisResult, Value,
+};
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::hash::{BuildHasher, Hasher};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+use crate::types::{ConnectionMetrics, ConnectionRetryConfig, LatencyBucket, TransientRetryConfig};
+
+/// Number of latency buckets; bucket `i` holds round trips shorter than 2^(i+1) µs
+const LATENCY_BUCKETS: usize = 32;
+
+/// Counters collected for one connection
+#[derive(Default)]
+struct Metrics {
+    commands: u64,
+    errors: u64,
+    bytes_out: u64,
+    bytes_in: u64,
+    latency: [u64; LATENCY_BUCKETS],
+}
+
+impl Metrics {
+    fn record(&mut self, started: Instant, commands: usize, bytes_out: usize, replies: Option<&[Value]>) {
+        let micros = started.elapsed().as_micros() as u64;
+        let bucket = (u64::BITS - micros.max(1).leading_zeros()) as usize - 1;
+        self.latency[bucket.min(LATENCY_BUCKETS - 1)] += 1;
+
+        self.commands += commands as u64;
+        self.bytes_out += bytes_out as u64;
+        match replies {
+            Some(replies) => {
+                for reply in replies {
+                    self.bytes_in += resp_size(reply);
+                    if matches!(reply, Value::ServerError(_)) {
+                        self.errors += 1;
+                    }
+                }
+            }
+            None => self.errors += 1,
+        }
+    }
+
+    /// Upper bound (in ms) of the bucket containing the given quantile
+    fn percentile(&self, quantile: f64) -> f64 {
+        let total: u64 = self.latency.iter().sum();
+        if total == 0 {
+            return 0.0;
+        }
+
+        let rank = ((total as f64) * quantile).ceil().max(1.0) as u64;
+        let mut seen = 0;
+        for (bucket, count) in self.latency.iter().enumerate() {
+            seen += count;
+            if seen >= rank {
+                return bucket_upper_bound_ms(bucket);
+            }
+        }
+        bucket_upper_bound_ms(LATENCY_BUCKETS - 1)
+    }
+}
+
+fn bucket_upper_bound_ms(bucket: usize) -> f64 {
+    (1u64 << (bucket + 1)) as f64 / 1000.0
+}
+
+/// Number of digits of a length in a RESP header
+fn digits(len: usize) -> u64 {
+    len.checked_ilog10().unwrap_or(0) as u64 + 1
+}
+
+/// Size of a command once packed, without packing it
+fn packed_len(cmd: &Cmd) -> usize {
+    let args = cmd.args_iter();
+    let header = 3 + digits(args.len());
+    let body: u64 = args
+        .map(|arg| match arg {
+            redis::Arg::Simple(bytes) => 5 + digits(bytes.len()) + bytes.len() as u64,
+            // Cursor placeholder, written as a u64
+            _ => 25,
+        })
+        .sum();
+    (header + body) as usize
+}
+
+/// Approximate size of a reply on the wire (RESP2 framing)
+fn resp_size(value: &Value) -> u64 {
+    // Type byte, length digits and CRLFs of a bulk header + payload
+    let bulk = |len: usize| len as u64 + 5 + digits(len);
+    match value {
+        Value::Nil => 5,
+        Value::Int(_) | Value::Boolean(_) | Value::Double(_) => 8,
+        Value::Okay => 5,
+        Value::SimpleString(s) => s.len() as u64 + 3,
+        Value::BulkString(bytes) => bulk(bytes.len()),
+        Value::VerbatimString { text, .. } => bulk(text.len()),
+        Value::Array(items) | Value::Set(items) => bulk(0) + items.iter().map(resp_size).sum::<u64>(),
+        Value::Map(pairs) => {
+            bulk(0) + pairs.iter().map(|(k, v)| resp_size(k) + resp_size(v)).sum::<u64>()
+        }
+        Value::Attribute { data, .. } => resp_size(data),
+        Value::Push { data, .. } => bulk(0) + data.iter().map(resp_size).sum::<u64>(),
+        Value::ServerError(err) => err.details().map_or(0, str::len) as u64 + 16,
+        // BigNumber and future reply types
+        _ => 8,
+    }
+}
+
+/// Server error codes meaning the command was not executed and may succeed later
+const TRANSIENT_ERROR_CODES: [&str; 4] = ["LOADING", "BUSY", "MASTERDOWN", "CLUSTERDOWN"];
+
+fn is_transient(result: &RedisResult<Value>) -> bool {
+    matches!(result, Ok(Value::ServerError(err)) if TRANSIENT_ERROR_CODES.contains(&err.code()))
+}
+
+/// Resolved retry budget for transient server errors
+#[derive(Clone, Copy)]
+pub(crate) struct TransientRetry {
+    max_attempts: u32,
+    initial_delay: Duration,
+    max_delay: Duration,
+}
+
+impl TransientRetry {
+    pub(crate) fn from_config(config: &TransientRetryConfig) -> Self {
+        TransientRetry {
+            max_attempts: config.max_attempts.unwrap_or(5),
+            initial_delay: Duration::from_millis(config.initial_delay_ms.unwrap_or(100) as u64),
+            max_delay: Duration::from_millis(config.max_delay_ms.unwrap_or(2000) as u64),
+        }
+    }
+
+    fn delay(&self, attempt: u32) -> Duration {
+        self.initial_delay
+            .saturating_mul(1 << attempt.min(16))
+            .min(self.max_delay)
+    }
+}
+
+/// Commands that change the data again, or reply differently, when they are executed twice
+const NON_IDEMPOTENT_COMMANDS: [&str; 50] = [
+    "APPEND", "BLMOVE", "BLMPOP", "BLPOP", "BRPOP", "BRPOPLPUSH", "BZMPOP", "BZPOPMAX", "BZPOPMIN", "DECR",
+    "DECRBY", "EVAL", "EVALSHA", "EXEC", "FCALL", "GETDEL", "GETSET", "HINCRBY", "HINCRBYFLOAT", "HSETNX",
+    "INCR", "INCRBY", "INCRBYFLOAT", "LINSERT", "LMOVE", "LMPOP", "LPOP", "LPUSH", "LPUSHX", "MOVE",
+    "MSETNX", "PUBLISH", "RENAME", "RENAMENX", "RPOP", "RPOPLPUSH", "RPUSH", "RPUSHX", "SETNX", "SMOVE",
+    "SPOP", "SPUBLISH", "XADD", "XAUTOCLAIM", "XCLAIM", "XREADGROUP", "ZINCRBY", "ZMPOP", "ZPOPMAX", "ZPOPMIN",
+];
+
+/// Whether the command made of `args` may be sent again after a connection failure
+///
+/// SET is only safe without NX (a second attempt would find the key set by
+/// the first one) and without GET (it would return the new value).
+fn is_idempotent(args: &[&[u8]]) -> bool {
+    let Some((name, options)) = args.split_first() else {
+        return false;
+    };
+    if name.eq_ignore_ascii_case(b"SET") {
+        // SET key value [NX | XX] [GET] [EX seconds | ...]
+        return !options
+            .iter()
+            .skip(2)
+            .any(|option| option.eq_ignore_ascii_case(b"NX") || option.eq_ignore_ascii_case(b"GET"));
+    }
+    !NON_IDEMPOTENT_COMMANDS
+        .iter()
+        .any(|command| command.as_bytes().eq_ignore_ascii_case(name))
+}
+
+/// Arguments of a RESP-encoded command: `*<argc>\r\n$<len>\r\n<arg>\r\n...`
+///
+/// Returns None for anything that is not a single well-formed command.
+fn packed_command_args(cmd: &[u8]) -> Option<Vec<&[u8]>> {
+    fn header(cmd: &[u8], prefix: u8) -> Option<(usize, &[u8])> {
+        let end = cmd.windows(2).position(|w| w == b"\r\n")?;
+        let (&first, digits) = cmd[..end].split_first()?;
+        if first != prefix {
+            return None;
+        }
+        let n = std::str::from_utf8(digits).ok()?.parse().ok()?;
+        Some((n, &cmd[end + 2..]))
+    }
+
+    let (argc, mut rest) = header(cmd, b'*')?;
+    let mut args = Vec::with_capacity(argc);
+    for _ in 0..argc {
+        let (len, tail) = header(rest, b'$')?;
+        args.push(tail.get(..len)?);
+        rest = tail.get(len + 2..)?;
+    }
+    rest.is_empty().then_some(args)
+}
+
+fn is_connection_failure(result: &RedisResult<Value>) -> bool {
+    matches!(result, Err(err) if err.is_io_error())
+}
+
+/// Resolved retry budget for connection failures
+#[derive(Clone, Copy)]
+pub(crate) struct ConnectionRetry {
+    max_attempts: u32,
+    initial_delay: Duration,
+    max_delay: Duration,
+    jitter: bool,
+    non_idempotent: bool,
+}
+
+impl ConnectionRetry {
+    pub(crate) fn from_config(config: &ConnectionRetryConfig) -> Self {
+        ConnectionRetry {
+            max_attempts: config.max_attempts.unwrap_or(3),
+            initial_delay: Duration::from_millis(config.initial_delay_ms.unwrap_or(100) as u64),
+            max_delay: Duration::from_millis(config.max_delay_ms.unwrap_or(2000) as u64),
+            jitter: config.jitter.unwrap_or(true),
+            non_idempotent: config.retry_non_idempotent.unwrap_or(false),
+        }
+    }
+
+    fn delay(&self, attempt: u32) -> Duration {
+        let delay = self
+            .initial_delay
+            .saturating_mul(1 << attempt.min(16))
+            .min(self.max_delay);
+        if !self.jitter {
+            return delay;
+        }
+
+        let mut hasher = std::collections::hash_map::RandomState::new().build_hasher();
+        hasher.write_u32(attempt);
+        let fraction = (hasher.finish() % 1000) as u32;
+        delay / 2 + delay / 2 * fraction / 1000
+    }
+
+    /// Whether a command may be sent again; `idempotent` is its own verdict
+    fn allows(&self, idempotent: bool) -> bool {
+        self.non_idempotent || idempotent
+    }
+}
+
+/// Following of cluster MOVED/ASK redirections
+#[derive(Clone)]
+pub(crate) struct RedirectPolicy {
+    max_hops: u32,
+    /// Settings (credentials, TLS) used to connect to the nodes redirected to
+    info: ConnectionInfo,
+}
+
+impl RedirectPolicy {
+    pub(crate) fn new(max_hops: u32, info: ConnectionInfo) -> Self {
+        RedirectPolicy { max_hops, info }
+    }
+
+    /// Connection settings for `host:port`, keeping credentials and TLS settings
+    fn node_info(&self, host: &str, port: u16) -> Option<ConnectionInfo> {
+        let addr = match self.info.addr() {
+            ConnectionAddr::Tcp(..) => ConnectionAddr::Tcp(host.to_string(), port),
+            ConnectionAddr::TcpTls {
+                insecure, tls_params, ..
+            } => ConnectionAddr::TcpTls {
+                host: host.to_string(),
+                port,
+                insecure: *insecure,
+                tls_params: tls_params.clone(),
+            },
+            _ => return None,
+        };
+        Some(self.info.clone().set_addr(addr))
+    }
+}
+
+/// Opens a connection to the server that should replace the current one
+pub(crate) type Reconnect = Arc<dyn Fn() -> RedisResult<Connection> + Send + Sync>;
+
+/// Redirection carried by a MOVED or ASK error reply
+struct Redirect {
+    ask: bool,
+    host: Option<String>,
+    port: u16,
+}
+
+fn redirect_of(result: &RedisResult<Value>) -> Option<Redirect> {
+    let Ok(Value::ServerError(err)) = result else {
+        return None;
+    };
+    let ask = match err.code() {
+        "MOVED" => false,
+        "ASK" => true,
+        _ => return None,
+    };
+
+    // "<slot> <host>:<port>"; the host is empty when it is the same as the current node's
+    let endpoint = err.details()?.split_whitespace().nth(1)?;
+    let (host, port) = endpoint.rsplit_once(':')?;
+    Some(Redirect {
+        ask,
+        host: (!host.is_empty() && host != "?").then(|| host.to_string()),
+        port: port.parse().ok()?,
+    })
+}
+
+/// State shared by all clones of a MeteredConnection
+struct Shared {
+    /// None until the first command when the connection is lazy
+    conn: Option<Connection>,
+    metrics: Metrics,
+    /// Currently selected database index
+    db: i64,
+    redirects: Option<RedirectPolicy>,
+    /// Connections opened to nodes redirected to, by "host:port"
+    nodes: HashMap<String, Connection>,
+    reconnect: Option<Reconnect>,
+    /// Reconnect by itself when the server goes away or becomes read-only
+    auto_reconnect: bool,
+    /// Name set with CLIENT SETNAME, applied again after reconnecting
+    client_name: Option<String>,
+    /// Socket timeouts, applied again after reconnecting
+    read_timeout: Option<Duration>,
+    write_timeout: Option<Duration>,
+}
+
+impl Shared {
+    /// Open a new socket through the reconnect hook and restore the session
+    /// state: selected database, client name and socket timeouts
+    fn reopen(&mut self) -> RedisResult<()> {
+        let reconnect = self.reconnect.clone().ok_or_else(|| {
+            RedisError::from((ErrorKind::Client, "Reconnecting is not supported by this connection"))
+        })?;
+
+        let mut conn = reconnect()?;
+        redis::cmd("SELECT").arg(self.db).query::<()>(&mut conn)?;
+        if let Some(name) = &self.client_name {
+            redis::cmd("CLIENT").arg("SETNAME").arg(name).query::<()>(&mut conn)?;
+        }
+        conn.set_read_timeout(self.read_timeout)?;
+        conn.set_write_timeout(self.write_timeout)?;
+        self.conn = Some(conn);
+        Ok(())
+    }
+
+    /// The socket, opened through the reconnect hook if not connected yet
+    fn conn(&mut self) -> RedisResult<&mut Connection> {
+        if self.conn.is_none() {
+            self.reopen()?;
+        }
+        Ok(self.conn.as_mut().expect("connection opened by reopen()"))
+    }
+
+    /// Replace the socket when the server stepped down or the connection broke
+    ///
+    /// Returns true when the command was rejected without being executed
+    /// (READONLY) and can be sent again on the new connection.
+    fn recover(&mut self, result: Result<&Value, &RedisError>) -> bool {
+        if !self.auto_reconnect {
+            return false;
+        }
+        let resend = match result {
+            Ok(Value::ServerError(err)) if err.code() == "READONLY" => true,
+            // The command may have been executed, so it is not sent again
+            Err(err) if err.is_io_error() || err.is_connection_dropped() => false,
+            _ => return false,
+        };
+        self.reopen().is_ok() && resend
+    }
+
+    /// Remember the name set by a successful CLIENT SETNAME, to restore it after reconnecting
+    fn track_client_name(&mut self, cmd: &Cmd, result: &RedisResult<Value>) {
+        if !matches!(result, Ok(Value::Okay)) {
+            return;
+        }
+        let mut args = cmd.args_iter().filter_map(|arg| match arg {
+            redis::Arg::Simple(arg) => Some(arg),
+            _ => None,
+        });
+        if let (Some(command), Some(subcommand), Some(name)) = (args.next(), args.next(), args.next()) {
+            if command.eq_ignore_ascii_case(b"CLIENT") && subcommand.eq_ignore_ascii_case(b"SETNAME") {
+                self.client_name = (!name.is_empty()).then(|| String::from_utf8_lossy(name).into_owned());
+            }
+        }
+    }
+
+    /// Follow MOVED/ASK replies to `cmd`, up to the policy's hop limit
+    ///
+    /// Returns the last reply; a redirect left unfollowed is returned as is.
+    fn follow_redirects(&mut self, cmd: &[u8], mut result: RedisResult<Value>) -> RedisResult<Value> {
+        let Some(policy) = self.redirects.clone() else {
+            return result;
+        };
+
+        for _ in 0..policy.max_hops {
+            let Some(redirect) = redirect_of(&result) else {
+                break;
+            };
+            let host = match redirect.host {
+                Some(host) => host,
+                None => match policy.info.addr() {
+                    ConnectionAddr::Tcp(host, _) | ConnectionAddr::TcpTls { host, .. } => host.clone(),
+                    _ => break,
+                },
+            };
+            let Some(info) = policy.node_info(&host, redirect.port) else {
+                break;
+            };
+
+            let node_key = format!("{}:{}", host, redirect.port);
+            let node = match self.nodes.entry(node_key.clone()) {
+                Entry::Occupied(entry) => entry.into_mut(),
+                Entry::Vacant(entry) => entry.insert(redis::Client::open(info)?.get_connection()?),
+            };
+            if redirect.ask {
+                redis::cmd("ASKING").query::<()>(node)?;
+            }
+            result = node.req_packed_command(cmd);
+
+            // Reconnect on the next redirect rather than reuse a broken socket
+            if result.as_ref().is_err_and(|e| e.is_io_error()) {
+                self.nodes.remove(&node_key);
+            }
+        }
+        result
+    }
+}
+
+/// A Connection that records request metrics
+///
+/// Cloning it yields another handle to the same socket.
+#[derive(Clone)]
+pub(crate) struct MeteredConnection {
+    shared: Arc<Mutex<Shared>>,
+    retry: Option<TransientRetry>,
+    connection_retry: Option<ConnectionRetry>,
+}
+
+impl MeteredConnection {
+    pub(crate) fn new(conn: Connection, db: i64, retry: Option<TransientRetry>) -> Self {
+        Self::open(Some(conn), db, retry)
+    }
+
+    /// A connection whose socket is opened on the first command
+    ///
+    /// Commands fail until a reconnect hook is set with `set_reconnect()`.
+    pub(crate) fn lazy(db: i64, retry: Option<TransientRetry>) -> Self {
+        Self::open(None, db, retry)
+    }
+
+    fn open(conn: Option<Connection>, db: i64, retry: Option<TransientRetry>) -> Self {
+        MeteredConnection {
+            shared: Arc::new(Mutex::new(Shared {
+                conn,
+                metrics: Metrics::default(),
+                db,
+                redirects: None,
+                nodes: HashMap::new(),
+                reconnect: None,
+                auto_reconnect: false,
+                client_name: None,
+                read_timeout: None,
+                write_timeout: None,
+            })),
+            retry,
+            connection_retry: None,
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Shared> {
+        self.shared.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Send a single command, retrying it while it fails with a transient error
+    /// or, after reconnecting, with a connection failure
+    ///
+    /// `idempotent` tells whether the command may be resent after a
+    /// connection failure. The lock is released while waiting between attempts.
+    fn send_with_retry(
+        &self,
+        idempotent: bool,
+        mut send: impl FnMut(&mut Shared) -> RedisResult<Value>,
+    ) -> RedisResult<Value> {
+        let mut result = send(&mut self.lock());
+        let mut transient_attempts = 0;
+        let mut connection_attempts = 0;
+
+        loop {
+            let delay = if let Some(retry) = self
+                .retry
+                .filter(|retry| transient_attempts < retry.max_attempts && is_transient(&result))
+            {
+                transient_attempts += 1;
+                retry.delay(transient_attempts - 1)
+            } else if let Some(retry) = self.connection_retry.filter(|retry| {
+                connection_attempts < retry.max_attempts && is_connection_failure(&result) && retry.allows(idempotent)
+            }) {
+                connection_attempts += 1;
+                retry.delay(connection_attempts - 1)
+            } else {
+                return result;
+            };
+
+            std::thread::sleep(delay);
+            result = send(&mut self.lock());
+        }
+    }
+
+    /// Resend commands that failed with a connection failure according to `retry`
+    ///
+    /// The socket is only reopened if a reconnect hook is set.
+    pub(crate) fn set_connection_retry(&mut self, retry: Option<ConnectionRetry>) {
+        self.connection_retry = retry;
+    }
+
+    /// Follow cluster redirections according to `policy`, or stop following them
+    pub(crate) fn set_redirects(&self, policy: Option<RedirectPolicy>) {
+        let mut shared = self.lock();
+        shared.redirects = policy;
+        shared.nodes.clear();
+    }
+
+    /// Reopen the socket through `reconnect`
+    ///
+    /// With `automatic`, this also happens when the server goes away or
+    /// becomes read-only; otherwise only on `reconnect()`.
+    pub(crate) fn set_reconnect(&self, reconnect: Reconnect, automatic: bool) {
+        let mut shared = self.lock();
+        shared.reconnect = Some(reconnect);
+        shared.auto_reconnect = automatic;
+    }
+
+    /// Record the name the socket was given with CLIENT SETNAME, so that it is
+    /// set again on reconnecting (or on connecting, for a lazy connection)
+    pub(crate) fn set_client_name(&self, name: Option<String>) {
+        self.lock().client_name = name;
+    }
+
+    /// Record the socket timeouts the connection was opened with, so that
+    /// they are set again on reconnecting (or on connecting, for a lazy connection)
+    pub(crate) fn record_timeouts(&self, read: Option<Duration>, write: Option<Duration>) {
+        let mut shared = self.lock();
+        shared.read_timeout = read;
+        shared.write_timeout = write;
+    }
+
+    /// Open a new socket and restore the session state on it
+    pub(crate) fn reconnect(&self) -> RedisResult<()> {
+        self.lock().reopen()
+    }
+
+    /// Fail reads that wait longer than `timeout`; None waits forever
+    pub(crate) fn set_read_timeout(&self, timeout: Option<Duration>) -> RedisResult<()> {
+        let mut shared = self.lock();
+        if let Some(conn) = &shared.conn {
+            conn.set_read_timeout(timeout)?;
+        }
+        shared.read_timeout = timeout;
+        Ok(())
+    }
+
+    /// Fail writes that wait longer than `timeout`; None waits forever
+    pub(crate) fn set_write_timeout(&self, timeout: Option<Duration>) -> RedisResult<()> {
+        let mut shared = self.lock();
+        if let Some(conn) = &shared.conn {
+            conn.set_write_timeout(timeout)?;
+        }
+        shared.write_timeout = timeout;
+        Ok(())
+    }
+
+    /// Index of the selected database
+    pub(crate) fn db(&self) -> i64 {
+        self.lock().db
+    }
+
+    /// Record the database selected with SELECT
+    pub(crate) fn set_db(&self, db: i64) {
+        self.lock().db = db;
+    }
+
+    /// Snapshot of the collected metrics
+    pub(crate) fn snapshot(&self) -> ConnectionMetrics {
+        let shared = self.lock();
+        let metrics = &shared.metrics;
+        ConnectionMetrics {
+            commands: metrics.commands as i64,
+            errors: metrics.errors as i64,
+            bytes_out: metrics.bytes_out as i64,
+            bytes_in: metrics.bytes_in as i64,
+            latency_p50_ms: metrics.percentile(0.50),
+            latency_p95_ms: metrics.percentile(0.95),
+            latency_p99_ms: metrics.percentile(0.99),
+            latency_histogram: metrics
+                .latency
+                .iter()
+                .enumerate()
+                .filter(|(_, count)| **count > 0)
+                .map(|(bucket, count)| LatencyBucket {
+                    upper_bound_ms: bucket_upper_bound_ms(bucket),
+                    count: *count as i64,
+                })
+                .collect(),
+        }
+    }
+
+    pub(crate) fn reset(&self) {
+        self.lock().metrics = Metrics::default();
+    }
+
+    /// Send a PING whenever no command was sent for `interval`
+    ///
+    /// The heartbeat thread exits once every handle to the connection is dropped.
+    pub(crate) fn start_heartbeat(&self, interval: Duration) {
+        let shared = Arc::downgrade(&self.shared);
+        std::thread::spawn(move || {
+            let mut last_commands = None;
+            loop {
+                std::thread::sleep(interval);
+                let Some(shared) = shared.upgrade() else {
+                    break;
+                };
+                let mut shared = shared.lock().unwrap_or_else(|e| e.into_inner());
+
+                let commands = shared.metrics.commands;
+                let restore = shared.read_timeout;
+                // A lazy connection has nothing to keep alive until it is used
+                if let Some(conn) = shared.conn.as_mut().filter(|_| last_commands == Some(commands)) {
+                    // Don't let a dead peer hold the lock forever; a failure
+                    // shows up on the next command, which reconnects if configured
+                    let _ = conn.set_read_timeout(Some(restore.map_or(interval, |t| t.min(interval))));
+                    let _ = conn.req_command(&redis::cmd("PING"));
+                    let _ = conn.set_read_timeout(restore);
+                }
+                last_commands = Some(commands);
+            }
+        });
+    }
+}
+
+impl ConnectionLike for MeteredConnection {
+    fn req_command(&mut self, cmd: &Cmd) -> RedisResult<Value> {
+        let args: Option<Vec<&[u8]>> = cmd
+            .args_iter()
+            .map(|arg| match arg {
+                redis::Arg::Simple(arg) => Some(arg),
+                _ => None,
+            })
+            .collect();
+        self.send_with_retry(args.is_some_and(|args| is_idempotent(&args)), |shared| {
+            let started = Instant::now();
+            let mut result = shared.conn().and_then(|conn| conn.req_command(cmd));
+            if shared.recover(result.as_ref()) {
+                result = shared.conn().and_then(|conn| conn.req_command(cmd));
+            }
+            shared.track_client_name(cmd, &result);
+            shared.metrics
+                .record(started, 1, packed_len(cmd), result.as_ref().ok().map(std::slice::from_ref));
+            if redirect_of(&result).is_some() {
+                return shared.follow_redirects(&cmd.get_packed_command(), result);
+            }
+            result
+        })
+    }
+
+    fn req_packed_command(&mut self, cmd: &[u8]) -> RedisResult<Value> {
+        let idempotent = packed_command_args(cmd).is_some_and(|args| is_idempotent(&args));
+        self.send_with_retry(idempotent, |shared| {
+            let started = Instant::now();
+            let mut result = shared.conn().and_then(|conn| conn.req_packed_command(cmd));
+            if shared.recover(result.as_ref()) {
+                result = shared.conn().and_then(|conn| conn.req_packed_command(cmd));
+            }
+            shared.metrics
+                .record(started, 1, cmd.len(), result.as_ref().ok().map(std::slice::from_ref));
+            shared.follow_redirects(cmd, result)
+        })
+    }
+
+    fn req_packed_commands(&mut self, cmd: &[u8], offset: usize, count: usize) -> RedisResult<Vec<Value>> {
+        let mut shared = self.lock();
+        let started = Instant::now();
+        let result = shared
+            .conn()
+            .and_then(|conn| conn.req_packed_commands(cmd, offset, count));
+        shared
+            .metrics
+            .record(started, count, cmd.len(), result.as_ref().ok().map(Vec::as_slice));
+        // Reconnect for the next batch; this one is not sent again
+        shared.recover(result.as_ref().map(|_| &Value::Nil));
+        result
+    }
+
+    fn get_db(&self) -> i64 {
+        let shared = self.lock();
+        shared.conn.as_ref().map_or(shared.db, Connection::get_db)
+    }
+
+    fn supports_pipelining(&self) -> bool {
+        self.lock().conn.as_ref().is_none_or(Connection::supports_pipelining)
+    }
+
+    fn check_connection(&mut self) -> bool {
+        self.lock().conn().is_ok_and(|conn| conn.check_connection())
+    }
+
+    /// A lazy connection counts as open until connecting fails
+    fn is_open(&self) -> bool {
+        self.lock().conn.as_ref().is_none_or(Connection::is_open)
+    }
+}
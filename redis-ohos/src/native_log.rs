@@ -2,19 +2,44 @@ use napi_derive_ohos::napi;
 use ohos_hilog_binding::{
     hilog_debug, hilog_error, hilog_info, hilog_warn, set_global_options, LogOptions,
 };
+use std::backtrace::Backtrace;
 use std::collections::HashMap;
 use std::panic;
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
 use tracing::{Event, Subscriber};
 use tracing_core::Level;
 use tracing_subscriber::layer::{Context, Layer};
 use tracing_subscriber::prelude::*;
 
+use crate::types::PanicInfo;
+
 static PANIC_HOOK_INITIALIZED: std::sync::Once = std::sync::Once::new();
 static TRACING_INITIALIZED: std::sync::Once = std::sync::Once::new();
 static HILOG_INITIALIZED: std::sync::Once = std::sync::Once::new();
+static LAST_PANIC: Mutex<Option<PanicInfo>> = Mutex::new(None);
 
 fn panic_hook(info: &panic::PanicHookInfo) {
     hilog_error!("RUST PANIC: {}", info);
+
+    let payload = info.payload();
+    let message = payload
+        .downcast_ref::<&str>()
+        .map(|s| s.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "Box<dyn Any>".to_string());
+    let timestamp_ms = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_millis() as i64);
+
+    let panic_info = PanicInfo {
+        message,
+        location: info.location().map(|l| l.to_string()),
+        thread: std::thread::current().name().map(str::to_string),
+        backtrace: Backtrace::force_capture().to_string(),
+        timestamp_ms,
+    };
+    *LAST_PANIC.lock().unwrap_or_else(|e| e.into_inner()) = Some(panic_info);
 }
 
 #[napi]
@@ -24,6 +49,25 @@ pub fn init_panic_hook() {
     });
 }
 
+/// Get details of the most recent panic caught by the panic hook
+///
+/// Requires `initPanicHook()` (or `initLogging()`) to have been called.
+///
+/// # Returns
+/// The panic message, location, thread and backtrace, or null if no panic happened
+///
+/// # Example (ArkTS)
+/// ```typescript
+/// const panic = getLastPanicInfo();
+/// if (panic !== null) {
+///   report.attach("native_panic", `${panic.message} at ${panic.location}\n${panic.backtrace}`);
+/// }
+/// ```
+#[napi]
+pub fn get_last_panic_info() -> Option<PanicInfo> {
+    LAST_PANIC.lock().unwrap_or_else(|e| e.into_inner()).clone()
+}
+
 #[napi]
 pub fn hilog_global_options(domain: u32, tag: String) {
     HILOG_INITIALIZED.call_once(|| {
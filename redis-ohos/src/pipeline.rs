@@ -0,0 +1,272 @@
+// Pipeline for HarmonyOS NAPI
+//
+// Commands are queued on a RedisPipeline and sent in a single round trip by
+// `execute()`, which returns one result per command.
+
+use napi_derive_ohos::napi;
+use napi_ohos::bindgen_prelude::*;
+use redis::{ConnectionLike, Pipeline, Value};
+
+use crate::error::redis_error;
+use crate::metrics::MeteredConnection;
+use crate::types::RedisValue;
+
+/// Default number of commands sent per round trip
+pub(crate) const DEFAULT_PIPELINE_CHUNK: usize = 1000;
+
+/// Split a pipeline into pipelines of at most `size` commands
+///
+/// Each chunk is only built when iterated, so at most one chunk's request
+/// buffer exists at a time.
+pub(crate) fn chunks(pipe: &Pipeline, size: usize) -> impl Iterator<Item = Pipeline> + '_ {
+    let mut cmds = pipe.cmd_iter().peekable();
+    std::iter::from_fn(move || {
+        cmds.peek()?;
+        let mut chunk = Pipeline::with_capacity(size);
+        for cmd in cmds.by_ref().take(size) {
+            chunk.add_command(cmd.clone());
+        }
+        Some(chunk)
+    })
+}
+
+/// Validate a chunk size passed from ArkTS
+pub(crate) fn chunk_size(size: u32) -> Result<usize> {
+    if size == 0 {
+        return Err(napi_ohos::Error::new(Status::InvalidArg, "Chunk size must be greater than 0"));
+    }
+    Ok(size as usize)
+}
+
+/// Batch of commands sent in one round trip
+///
+/// Created with `RedisConnection.pipeline()`. Command methods return the
+/// pipeline, so calls can be chained. The pipeline shares the socket of the
+/// connection it was created from.
+///
+/// Large batches are sent in chunks of `chunkSize()` commands (1000 by
+/// default), one round trip each, so a huge pipeline never becomes a single
+/// huge request buffer. Results are still returned as one array.
+///
+/// # Example (ArkTS)
+/// ```typescript
+/// const results = conn.pipeline()
+///   .set("user:1:name", "Alice")
+///   .expire("user:1:name", 3600)
+///   .incr("users:count")
+///   .execute();
+/// ```
+#[napi]
+pub struct RedisPipeline {
+    conn: MeteredConnection,
+    pipe: Pipeline,
+    chunk_size: usize,
+}
+
+impl RedisPipeline {
+    pub(crate) fn new(conn: MeteredConnection) -> Self {
+        RedisPipeline {
+            conn,
+            pipe: redis::pipe(),
+            chunk_size: DEFAULT_PIPELINE_CHUNK,
+        }
+    }
+
+    /// Pipeline whose commands run in a MULTI/EXEC transaction
+    pub(crate) fn transaction(conn: MeteredConnection) -> Self {
+        let mut pipe = redis::pipe();
+        pipe.atomic();
+        RedisPipeline {
+            conn,
+            pipe,
+            chunk_size: DEFAULT_PIPELINE_CHUNK,
+        }
+    }
+
+    /// Take the queued commands, leaving an empty pipeline in the same mode
+    fn take_pipe(&mut self) -> Pipeline {
+        let mut fresh = redis::pipe();
+        if self.pipe.is_transaction() {
+            fresh.atomic();
+        }
+        std::mem::replace(&mut self.pipe, fresh)
+    }
+
+    /// Send the queued commands
+    ///
+    /// Returns None when a transaction was aborted because a watched key changed.
+    pub(crate) fn send(&mut self) -> Result<Option<Vec<RedisValue>>> {
+        let pipe = self.take_pipe();
+        if pipe.is_empty() && !pipe.is_transaction() {
+            return Ok(Some(Vec::new()));
+        }
+
+        let replies = if pipe.is_transaction() {
+            let packed = pipe.get_packed_pipeline();
+            // Skip the replies to MULTI and to each QUEUED command
+            let mut exec = self
+                .conn
+                .req_packed_commands(&packed, pipe.len() + 1, 1)
+                .map_err(|e| redis_error("EXEC", e))?;
+            match exec.pop() {
+                Some(Value::Array(replies)) => replies,
+                Some(Value::Nil) | None => return Ok(None),
+                Some(other) => vec![other],
+            }
+        } else {
+            let mut replies = Vec::with_capacity(pipe.len());
+            for chunk in chunks(&pipe, self.chunk_size) {
+                let packed = chunk.get_packed_pipeline();
+                replies.extend(
+                    self.conn
+                        .req_packed_commands(&packed, 0, chunk.len())
+                        .map_err(|e| redis_error("Pipeline", e))?,
+                );
+            }
+            replies
+        };
+
+        Ok(Some(
+            replies
+                .iter()
+                .map(|reply| RedisValue::from_redis_value(reply, false))
+                .collect(),
+        ))
+    }
+}
+
+#[napi]
+impl RedisPipeline {
+    /// Queue any Redis command
+    ///
+    /// # Arguments
+    /// * `command` - The Redis command name (e.g., "ZADD")
+    /// * `args` - Array of command arguments
+    #[napi]
+    pub fn cmd<'env>(&mut self, this: This<'env>, command: String, args: Vec<String>) -> This<'env> {
+        self.pipe.cmd(&command).arg(args);
+        this
+    }
+
+    /// Queue a SET command
+    #[napi]
+    pub fn set<'env>(&mut self, this: This<'env>, key: String, value: String) -> This<'env> {
+        self.pipe.set(key, value);
+        this
+    }
+
+    /// Queue a GET command
+    #[napi]
+    pub fn get<'env>(&mut self, this: This<'env>, key: String) -> This<'env> {
+        self.pipe.get(key);
+        this
+    }
+
+    /// Queue a DEL command
+    #[napi]
+    pub fn del<'env>(&mut self, this: This<'env>, keys: Vec<String>) -> This<'env> {
+        self.pipe.del(keys);
+        this
+    }
+
+    /// Queue an INCRBY command
+    ///
+    /// # Arguments
+    /// * `delta` - Amount to add (default: 1)
+    #[napi]
+    pub fn incr<'env>(&mut self, this: This<'env>, key: String, delta: Option<i64>) -> This<'env> {
+        self.pipe.incr(key, delta.unwrap_or(1));
+        this
+    }
+
+    /// Queue an EXPIRE command
+    #[napi]
+    pub fn expire<'env>(&mut self, this: This<'env>, key: String, seconds: i64) -> This<'env> {
+        self.pipe.expire(key, seconds);
+        this
+    }
+
+    /// Queue an HSET command
+    #[napi]
+    pub fn hset<'env>(&mut self, this: This<'env>, key: String, field: String, value: String) -> This<'env> {
+        self.pipe.hset(key, field, value);
+        this
+    }
+
+    /// Queue an HGET command
+    #[napi]
+    pub fn hget<'env>(&mut self, this: This<'env>, key: String, field: String) -> This<'env> {
+        self.pipe.hget(key, field);
+        this
+    }
+
+    /// Queue an HGETALL command
+    #[napi]
+    pub fn hgetall<'env>(&mut self, this: This<'env>, key: String) -> This<'env> {
+        self.pipe.hgetall(key);
+        this
+    }
+
+    /// Queue an RPUSH command
+    #[napi]
+    pub fn rpush<'env>(&mut self, this: This<'env>, key: String, values: Vec<String>) -> This<'env> {
+        self.pipe.rpush(key, values);
+        this
+    }
+
+    /// Queue an SADD command
+    #[napi]
+    pub fn sadd<'env>(&mut self, this: This<'env>, key: String, members: Vec<String>) -> This<'env> {
+        self.pipe.sadd(key, members);
+        this
+    }
+
+    /// Queue a ZADD command for one member
+    #[napi]
+    pub fn zadd<'env>(&mut self, this: This<'env>, key: String, member: String, score: f64) -> This<'env> {
+        self.pipe.zadd(key, member, score);
+        this
+    }
+
+    /// Run the queued commands in a MULTI/EXEC transaction
+    ///
+    /// The transaction is still sent in a single round trip. Applies to
+    /// every batch executed afterwards.
+    #[napi]
+    pub fn atomic<'env>(&mut self, this: This<'env>) -> This<'env> {
+        self.pipe.atomic();
+        this
+    }
+
+    /// Set the maximum number of commands sent per round trip
+    ///
+    /// Transactions are always sent in one round trip, whatever their size.
+    ///
+    /// # Arguments
+    /// * `size` - Commands per chunk (default: 1000)
+    #[napi]
+    pub fn chunk_size<'env>(&mut self, this: This<'env>, size: u32) -> Result<This<'env>> {
+        self.chunk_size = chunk_size(size)?;
+        Ok(this)
+    }
+
+    /// Number of queued commands
+    #[napi(getter)]
+    pub fn length(&self) -> u32 {
+        self.pipe.len() as u32
+    }
+
+    /// Send all queued commands in one round trip
+    ///
+    /// The pipeline is emptied and can be reused for the next batch. A
+    /// command rejected by the server does not fail the others; its result
+    /// is an Error reply.
+    ///
+    /// # Returns
+    /// Each reply, in queue order, or null if the pipeline is a transaction
+    /// aborted because a watched key changed
+    #[napi]
+    pub fn execute(&mut self) -> Result<Option<Vec<RedisValue>>> {
+        self.send()
+    }
+}
@@ -0,0 +1,297 @@
+// Presence tracker for HarmonyOS NAPI
+
+use napi_derive_ohos::napi;
+use napi_ohos::bindgen_prelude::*;
+use napi_ohos::threadsafe_function::{ThreadsafeFunction, ThreadsafeFunctionCallMode};
+use redis::{Connection, Script};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, LazyLock};
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+use crate::client::RedisClient;
+use crate::error::{redis_error, report_background_error};
+use crate::types::{PresenceEvent, PresenceEventKind};
+
+/// Record a heartbeat and announce the member if it wasn't online
+///
+/// KEYS[1] = online zset (score = last heartbeat in ms), KEYS[2] = member key
+/// ARGV[1] = member id, ARGV[2] = member TTL in ms, ARGV[3] = events channel
+/// Returns 1 if the member just came online
+static HEARTBEAT_SCRIPT: LazyLock<Script> = LazyLock::new(|| {
+    Script::new(
+        r#"
+local now = redis.call('TIME')
+local now_ms = tonumber(now[1]) * 1000 + math.floor(tonumber(now[2]) / 1000)
+local ttl = tonumber(ARGV[2])
+local joined = redis.call('EXISTS', KEYS[2]) == 0
+redis.call('SET', KEYS[2], now_ms, 'PX', ttl)
+redis.call('ZADD', KEYS[1], now_ms, ARGV[1])
+redis.call('ZREMRANGEBYSCORE', KEYS[1], '-inf', now_ms - ttl)
+if joined then
+  redis.call('PUBLISH', ARGV[3], 'join:' .. ARGV[1])
+  return 1
+end
+return 0
+"#,
+    )
+});
+
+/// Remove a member and announce it
+///
+/// KEYS[1] = online zset, KEYS[2] = member key
+/// ARGV[1] = member id, ARGV[2] = events channel
+static LEAVE_SCRIPT: LazyLock<Script> = LazyLock::new(|| {
+    Script::new(
+        r#"
+local removed = redis.call('DEL', KEYS[2])
+redis.call('ZREM', KEYS[1], ARGV[1])
+if removed == 1 then
+  redis.call('PUBLISH', ARGV[2], 'leave:' .. ARGV[1])
+end
+return removed
+"#,
+    )
+});
+
+/// Members with a heartbeat within ARGV[1] ms of the server clock
+///
+/// KEYS[1] = online zset
+static ONLINE_SCRIPT: LazyLock<Script> = LazyLock::new(|| {
+    Script::new(
+        r#"
+local now = redis.call('TIME')
+local now_ms = tonumber(now[1]) * 1000 + math.floor(tonumber(now[2]) / 1000)
+return redis.call('ZRANGEBYSCORE', KEYS[1], now_ms - tonumber(ARGV[1]), '+inf')
+"#,
+    )
+});
+
+/// Event watcher thread handle
+struct Watcher {
+    running: Arc<AtomicBool>,
+    handle: JoinHandle<()>,
+}
+
+/// Presence tracker based on heartbeats
+///
+/// Every heartbeat refreshes a per-member key with a TTL and the member's
+/// timestamp in a sorted set. A member is online while its key exists.
+/// Joins are published by the heartbeat itself; leaves are detected from
+/// expired-key notifications, so `watch()` requires keyspace notifications
+/// for expired events (`notify-keyspace-events Ex`).
+///
+/// # Example (ArkTS)
+/// ```typescript
+/// const presence = client.getPresenceTracker("chat-room-1", 15000);
+///
+/// // Every 5 seconds
+/// presence.heartbeat(userId);
+///
+/// const online = presence.listOnline();
+///
+/// presence.watch((event) => {
+///   console.log(`${event.member} ${event.kind === PresenceEventKind.Join ? "joined" : "left"}`);
+/// });
+/// ```
+#[napi]
+pub struct PresenceTracker {
+    client: RedisClient,
+    conn: Connection,
+    db: i64,
+    online: String,
+    member_prefix: String,
+    channel: String,
+    member_ttl_ms: u32,
+    watcher: Option<Watcher>,
+}
+
+impl PresenceTracker {
+    pub(crate) fn new(client: RedisClient, conn: Connection, db: i64, name: &str, member_ttl_ms: u32) -> Self {
+        PresenceTracker {
+            client,
+            conn,
+            db,
+            online: format!("{{{}}}:online", name),
+            member_prefix: format!("{{{}}}:member:", name),
+            channel: format!("{{{}}}:events", name),
+            member_ttl_ms,
+            watcher: None,
+        }
+    }
+}
+
+#[napi]
+impl PresenceTracker {
+    /// Record a heartbeat for a member
+    ///
+    /// # Arguments
+    /// * `id` - Member id
+    ///
+    /// # Returns
+    /// true if the member was offline and just came online
+    #[napi]
+    pub fn heartbeat(&mut self, id: String) -> Result<bool> {
+        let joined: i64 = HEARTBEAT_SCRIPT
+            .key(&self.online)
+            .key(format!("{}{}", self.member_prefix, id))
+            .arg(&id)
+            .arg(self.member_ttl_ms)
+            .arg(&self.channel)
+            .invoke(&mut self.conn)
+            .map_err(|e| redis_error("Presence heartbeat", e))?;
+        Ok(joined == 1)
+    }
+
+    /// Mark a member as offline immediately
+    ///
+    /// # Returns
+    /// true if the member was online
+    #[napi]
+    pub fn leave(&mut self, id: String) -> Result<bool> {
+        let removed: i64 = LEAVE_SCRIPT
+            .key(&self.online)
+            .key(format!("{}{}", self.member_prefix, id))
+            .arg(&id)
+            .arg(&self.channel)
+            .invoke(&mut self.conn)
+            .map_err(|e| redis_error("Presence leave", e))?;
+        Ok(removed == 1)
+    }
+
+    /// List members with a recent heartbeat
+    ///
+    /// # Arguments
+    /// * `within_ms` - Maximum age of the last heartbeat (default: member TTL)
+    ///
+    /// # Returns
+    /// Member ids, least recently seen first
+    #[napi]
+    pub fn list_online(&mut self, within_ms: Option<u32>) -> Result<Vec<String>> {
+        ONLINE_SCRIPT
+            .key(&self.online)
+            .arg(within_ms.unwrap_or(self.member_ttl_ms))
+            .invoke(&mut self.conn)
+            .map_err(|e| redis_error("Presence list", e))
+    }
+
+    /// Timestamp of a member's last heartbeat (server clock, in milliseconds)
+    ///
+    /// # Returns
+    /// The timestamp, or null if the member has no recent heartbeat
+    #[napi]
+    pub fn last_seen(&mut self, id: String) -> Result<Option<i64>> {
+        redis::cmd("ZSCORE")
+            .arg(&self.online)
+            .arg(id)
+            .query::<Option<f64>>(&mut self.conn)
+            .map(|score| score.map(|s| s as i64))
+            .map_err(|e| redis_error("Presence lookup", e))
+    }
+
+    /// Start delivering join/leave events to a callback
+    ///
+    /// Runs a background thread with its own subscriber connection. Leave
+    /// events for members that timed out arrive when Redis expires their key,
+    /// which can lag slightly behind the TTL.
+    ///
+    /// # Arguments
+    /// * `callback` - Called with each PresenceEvent on the ArkTS thread
+    #[napi]
+    pub fn watch(
+        &mut self,
+        callback: ThreadsafeFunction<PresenceEvent, Unknown<'static>, PresenceEvent, Status, false>,
+    ) -> Result<()> {
+        if self.watcher.is_some() {
+            return Err(napi_ohos::Error::from_reason("Presence watcher is already running"));
+        }
+
+        let mut conn = self.client.connect(None).map_err(|e| redis_error("Connect", e))?;
+        let expired_channel = format!("__keyevent@{}__:expired", self.db);
+        let channel = self.channel.clone();
+        let member_prefix = self.member_prefix.clone();
+
+        let running = Arc::new(AtomicBool::new(true));
+        let flag = running.clone();
+        // The subscription lives inside the thread (dropping PubSub unsubscribes);
+        // its outcome is reported back before watch() returns
+        let (ready_tx, ready_rx) = std::sync::mpsc::channel();
+
+        let handle = std::thread::spawn(move || {
+            let mut pubsub = conn.as_pubsub();
+            let subscribed = pubsub
+                .subscribe(&[channel.as_str(), expired_channel.as_str()])
+                .and_then(|_| pubsub.set_read_timeout(Some(Duration::from_millis(500))));
+            let failed = subscribed.is_err();
+            let _ = ready_tx.send(subscribed);
+            if failed {
+                return;
+            }
+
+            while flag.load(Ordering::Acquire) {
+                let msg = match pubsub.get_message() {
+                    Ok(msg) => msg,
+                    Err(e) if e.is_timeout() => continue,
+                    Err(e) => {
+                        report_background_error("Presence watcher", &redis_error("Presence watch", e));
+                        break;
+                    }
+                };
+
+                let payload = String::from_utf8_lossy(msg.get_payload_bytes());
+                let event = if msg.get_channel_name() == channel {
+                    match payload.split_once(':') {
+                        Some(("join", member)) => PresenceEvent {
+                            member: member.to_string(),
+                            kind: PresenceEventKind::Join,
+                        },
+                        Some(("leave", member)) => PresenceEvent {
+                            member: member.to_string(),
+                            kind: PresenceEventKind::Leave,
+                        },
+                        _ => continue,
+                    }
+                } else {
+                    match payload.strip_prefix(member_prefix.as_str()) {
+                        Some(member) => PresenceEvent {
+                            member: member.to_string(),
+                            kind: PresenceEventKind::Leave,
+                        },
+                        None => continue,
+                    }
+                };
+                callback.call(event, ThreadsafeFunctionCallMode::NonBlocking);
+            }
+        });
+
+        match ready_rx.recv() {
+            Ok(Ok(())) => {}
+            Ok(Err(e)) => {
+                let _ = handle.join();
+                return Err(redis_error("SUBSCRIBE", e));
+            }
+            Err(_) => {
+                let _ = handle.join();
+                return Err(napi_ohos::Error::from_reason("Presence watcher failed to start"));
+            }
+        }
+
+        self.watcher = Some(Watcher { running, handle });
+        Ok(())
+    }
+
+    /// Stop the event watcher started with `watch()`
+    #[napi]
+    pub fn unwatch(&mut self) {
+        if let Some(watcher) = self.watcher.take() {
+            watcher.running.store(false, Ordering::Release);
+            let _ = watcher.handle.join();
+        }
+    }
+}
+
+impl Drop for PresenceTracker {
+    fn drop(&mut self) {
+        self.unwatch();
+    }
+}
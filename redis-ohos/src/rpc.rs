@@ -0,0 +1,255 @@
+// Request/response RPC over pub/sub for HarmonyOS NAPI
+//
+// Requests are PUBLISHed as JSON envelopes carrying a unique reply key.
+// Servers push the JSON response onto that key, and the caller waits for it
+// with BLPOP, so callers don't need a subscriber connection of their own.
+
+use napi_derive_ohos::napi;
+use napi_ohos::bindgen_prelude::*;
+use napi_ohos::threadsafe_function::{ThreadsafeFunction, ThreadsafeFunctionCallMode};
+use redis::ConnectionLike;
+use serde::{Deserialize, Serialize};
+use std::hash::{BuildHasher, Hasher};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::mpsc;
+use std::sync::Arc;
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+use crate::client::RedisClient;
+use crate::error::{redis_error, report_background_error};
+
+/// Request envelope published on the RPC channel
+#[derive(Serialize, Deserialize)]
+struct RpcRequest {
+    /// Key the response is pushed to
+    reply_to: String,
+    /// Milliseconds the caller waits for the response
+    timeout_ms: u32,
+    payload: String,
+}
+
+/// Response envelope pushed to the reply key
+#[derive(Serialize, Deserialize)]
+struct RpcResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    payload: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+/// Build a reply key that is unique across processes and calls
+fn reply_key(channel: &str) -> String {
+    static SEQUENCE: AtomicU64 = AtomicU64::new(0);
+    let mut hasher = std::collections::hash_map::RandomState::new().build_hasher();
+    hasher.write_u32(std::process::id());
+    hasher.write_u64(SEQUENCE.fetch_add(1, Ordering::Relaxed));
+    format!("rpc:reply:{}:{:016x}", channel, hasher.finish())
+}
+
+/// Send a request and wait for the response on `conn`
+pub(crate) fn call(conn: &mut dyn ConnectionLike, channel: &str, payload: String, timeout_ms: u32) -> Result<String> {
+    let reply_to = reply_key(channel);
+    let request = serde_json::to_string(&RpcRequest {
+        reply_to: reply_to.clone(),
+        timeout_ms,
+        payload,
+    })
+    .map_err(|e| napi_ohos::Error::from_reason(format!("RPC request encoding failed: {}", e)))?;
+
+    let receivers: i64 = redis::cmd("PUBLISH")
+        .arg(channel)
+        .arg(request)
+        .query(conn)
+        .map_err(|e| redis_error("RPC publish", e))?;
+    if receivers == 0 {
+        return Err(napi_ohos::Error::from_reason(format!(
+            "No RPC server is listening on '{}'",
+            channel
+        )));
+    }
+
+    let reply: Option<(String, String)> = redis::cmd("BLPOP")
+        .arg(&reply_to)
+        .arg(timeout_ms.max(1) as f64 / 1000.0)
+        .query(conn)
+        .map_err(|e| redis_error("RPC wait", e))?;
+    let Some((_, reply)) = reply else {
+        return Err(napi_ohos::Error::from_reason(format!(
+            "RPC call on '{}' timed out after {} ms",
+            channel, timeout_ms
+        )));
+    };
+
+    let response: RpcResponse = serde_json::from_str(&reply)
+        .map_err(|e| napi_ohos::Error::from_reason(format!("Invalid RPC response: {}", e)))?;
+    match (response.payload, response.error) {
+        (_, Some(error)) => Err(napi_ohos::Error::from_reason(format!("RPC handler failed: {}", error))),
+        (Some(payload), None) => Ok(payload),
+        (None, None) => Ok(String::new()),
+    }
+}
+
+/// Handle of a running RPC server started with `RedisClient.rpcServe()`
+///
+/// # Example (ArkTS)
+/// ```typescript
+/// const server = client.rpcServe("math.double", (payload) => {
+///   return String(Number(payload) * 2);
+/// });
+///
+/// // In another thread or on another device
+/// const result = conn.rpcCall("math.double", "21", 3000); // "42"
+///
+/// server.stop();
+/// ```
+#[napi]
+pub struct RedisRpcServer {
+    channel: String,
+    running: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl RedisRpcServer {
+    pub(crate) fn start(
+        client: &RedisClient,
+        channel: String,
+        handler: ThreadsafeFunction<String, String, String, Status, false>,
+    ) -> Result<Self> {
+        let connect = || {
+            client.connect(None).map_err(|e| redis_error("Connect", e))
+        };
+        let mut sub_conn = connect()?;
+        let mut reply_conn = connect()?;
+
+        let running = Arc::new(AtomicBool::new(true));
+        let flag = running.clone();
+        let thread_channel = channel.clone();
+        // The subscription lives inside the thread (dropping PubSub unsubscribes);
+        // its outcome is reported back before start() returns
+        let (ready_tx, ready_rx) = mpsc::channel();
+
+        let handle = std::thread::spawn(move || {
+            let mut pubsub = sub_conn.as_pubsub();
+            let subscribed = pubsub
+                .subscribe(&thread_channel)
+                .and_then(|_| pubsub.set_read_timeout(Some(Duration::from_millis(500))));
+            let failed = subscribed.is_err();
+            let _ = ready_tx.send(subscribed);
+            if failed {
+                return;
+            }
+
+            while flag.load(Ordering::Acquire) {
+                let msg = match pubsub.get_message() {
+                    Ok(msg) => msg,
+                    Err(e) if e.is_timeout() => continue,
+                    Err(e) => {
+                        let context = format!("RPC receive on '{}'", thread_channel);
+                        report_background_error("RPC server", &redis_error(&context, e));
+                        break;
+                    }
+                };
+
+                let request: RpcRequest = match serde_json::from_slice(msg.get_payload_bytes()) {
+                    Ok(request) => request,
+                    Err(e) => {
+                        tracing::warn!("Ignoring malformed RPC request on '{}': {}", thread_channel, e);
+                        continue;
+                    }
+                };
+
+                let (result_tx, result_rx) = mpsc::channel();
+                handler.call_with_return_value(
+                    request.payload,
+                    ThreadsafeFunctionCallMode::NonBlocking,
+                    move |result, _env| {
+                        let _ = result_tx.send(result.map_err(|e| e.reason.clone()));
+                        Ok(())
+                    },
+                );
+
+                let response = match result_rx.recv_timeout(Duration::from_millis(request.timeout_ms as u64)) {
+                    Ok(Ok(payload)) => RpcResponse {
+                        payload: Some(payload),
+                        error: None,
+                    },
+                    Ok(Err(error)) => RpcResponse {
+                        payload: None,
+                        error: Some(error),
+                    },
+                    // The caller has given up already
+                    Err(_) => continue,
+                };
+
+                let Ok(response) = serde_json::to_string(&response) else {
+                    continue;
+                };
+                let pushed = redis::pipe()
+                    .cmd("RPUSH")
+                    .arg(&request.reply_to)
+                    .arg(response)
+                    .ignore()
+                    .cmd("PEXPIRE")
+                    .arg(&request.reply_to)
+                    .arg(request.timeout_ms.max(1))
+                    .ignore()
+                    .query::<()>(&mut reply_conn);
+                if let Err(e) = pushed {
+                    let context = format!("RPC reply on '{}'", thread_channel);
+                    report_background_error("RPC server", &redis_error(&context, e));
+                }
+            }
+        });
+
+        match ready_rx.recv() {
+            Ok(Ok(())) => {}
+            Ok(Err(e)) => {
+                let _ = handle.join();
+                return Err(redis_error("SUBSCRIBE", e));
+            }
+            Err(_) => {
+                let _ = handle.join();
+                return Err(napi_ohos::Error::from_reason("RPC server failed to start"));
+            }
+        }
+
+        Ok(RedisRpcServer {
+            channel,
+            running,
+            handle: Some(handle),
+        })
+    }
+}
+
+#[napi]
+impl RedisRpcServer {
+    /// Channel this server answers on
+    #[napi(getter)]
+    pub fn channel(&self) -> String {
+        self.channel.clone()
+    }
+
+    /// Whether the server is still running
+    #[napi(getter)]
+    pub fn is_running(&self) -> bool {
+        self.handle.as_ref().is_some_and(|handle| !handle.is_finished())
+    }
+
+    /// Stop answering requests
+    ///
+    /// Waits for the request being handled, if any, to finish.
+    #[napi]
+    pub fn stop(&mut self) {
+        if let Some(handle) = self.handle.take() {
+            self.running.store(false, Ordering::Release);
+            let _ = handle.join();
+        }
+    }
+}
+
+impl Drop for RedisRpcServer {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
@@ -0,0 +1,73 @@
+// Async runtime configuration for HarmonyOS NAPI
+//
+// Promise-returning methods run on a tokio runtime that napi-ohos creates
+// when the module is loaded, with one worker thread per CPU core.
+// `initRuntime()` replaces it with a runtime of the requested size.
+
+use napi_derive_ohos::napi;
+use napi_ohos::bindgen_prelude::*;
+use napi_ohos::tokio::runtime::Builder;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Set once `initRuntime()` has installed a runtime
+static RUNTIME_INITIALIZED: AtomicBool = AtomicBool::new(false);
+
+/// Configure the thread pool used by async commands
+///
+/// Call it once at startup, before any Promise-returning method: the
+/// default runtime is shut down and tasks still running on it are dropped.
+///
+/// # Arguments
+/// * `worker_threads` - Number of threads driving async commands (at least 1)
+/// * `max_blocking_threads` - Upper bound of the blocking thread pool (default: 512)
+///
+/// # Note
+/// `getConnectionAsync()` connects on the libuv thread pool, which is sized
+/// by the host and not affected by this setting.
+///
+/// # Example (ArkTS)
+/// ```typescript
+/// import { initRuntime } from 'libredis_ohos.so';
+///
+/// // Low-memory device: two threads are enough
+/// initRuntime(2, 4);
+/// const conn = await client.getAsyncConnection();
+/// ```
+#[napi]
+pub fn init_runtime(worker_threads: u32, max_blocking_threads: Option<u32>) -> Result<()> {
+    if worker_threads == 0 {
+        return Err(napi_ohos::Error::new(
+            Status::InvalidArg,
+            "worker_threads must be at least 1",
+        ));
+    }
+    if max_blocking_threads == Some(0) {
+        return Err(napi_ohos::Error::new(
+            Status::InvalidArg,
+            "max_blocking_threads must be at least 1",
+        ));
+    }
+    if RUNTIME_INITIALIZED.swap(true, Ordering::AcqRel) {
+        return Err(napi_ohos::Error::from_reason("Async runtime is already initialized"));
+    }
+
+    let mut builder = Builder::new_multi_thread();
+    builder
+        .worker_threads(worker_threads as usize)
+        .thread_name("redis-ohos-rt")
+        .enable_all();
+    if let Some(max) = max_blocking_threads {
+        builder.max_blocking_threads(max as usize);
+    }
+    let rt = builder.build().map_err(|e| {
+        RUNTIME_INITIALIZED.store(false, Ordering::Release);
+        napi_ohos::Error::from_reason(format!("Create async runtime failed: {}", e))
+    })?;
+
+    // The default runtime was started on module load; restarting it picks up
+    // the custom one
+    create_custom_tokio_runtime(rt);
+    shutdown_async_runtime();
+    start_async_runtime();
+    Ok(())
+}
@@ -0,0 +1,88 @@
+// Lua script wrapper for HarmonyOS NAPI
+
+use napi_derive_ohos::napi;
+use napi_ohos::bindgen_prelude::*;
+use redis::Script;
+
+use crate::connection::RedisConnection;
+use crate::error::redis_error;
+use crate::types::RedisValue;
+
+/// Lua script run by its SHA1 digest
+///
+/// `invoke()` calls EVALSHA and, if the server doesn't have the script
+/// cached yet (NOSCRIPT, e.g. after a restart or on a new server), loads it
+/// and retries transparently. The script source is only sent when needed.
+///
+/// # Example (ArkTS)
+/// ```typescript
+/// import { RedisScript } from 'libredis_ohos.so';
+///
+/// const rateLimit = new RedisScript(`
+///   local n = redis.call('INCR', KEYS[1])
+///   if n == 1 then redis.call('EXPIRE', KEYS[1], ARGV[1]) end
+///   return n
+/// `);
+///
+/// const count = rateLimit.invoke(conn, [`rate:${userId}`], ["60"]).int;
+/// ```
+#[napi]
+pub struct RedisScript {
+    inner: Script,
+}
+
+#[napi]
+impl RedisScript {
+    /// Create a script from its Lua source
+    #[napi(constructor)]
+    pub fn new(source: String) -> Self {
+        RedisScript {
+            inner: Script::new(&source),
+        }
+    }
+
+    /// SHA1 digest of the script, as used by EVALSHA
+    #[napi(getter)]
+    pub fn sha(&self) -> String {
+        self.inner.get_hash().to_string()
+    }
+
+    /// Load the script into the server's script cache
+    ///
+    /// Not required before `invoke()`; useful to warm up a new connection.
+    ///
+    /// # Returns
+    /// The SHA1 digest
+    #[napi]
+    pub fn load(&self, conn: &mut RedisConnection) -> Result<String> {
+        self.inner
+            .prepare_invoke()
+            .load(conn.metered())
+            .map_err(|e| redis_error("SCRIPT LOAD", e))
+    }
+
+    /// Run the script
+    ///
+    /// # Arguments
+    /// * `conn` - Connection to run the script on
+    /// * `keys` - Key names, available as KEYS in the script
+    /// * `args` - Additional arguments, available as ARGV in the script
+    ///
+    /// # Returns
+    /// The script's reply
+    #[napi]
+    pub fn invoke(&self, conn: &mut RedisConnection, keys: Vec<String>, args: Vec<String>) -> Result<RedisValue> {
+        let mut invocation = self.inner.prepare_invoke();
+        for key in keys {
+            invocation.key(key);
+        }
+        for arg in args {
+            invocation.arg(arg);
+        }
+
+        let result: redis::Value = invocation
+            .invoke(conn.metered())
+            .map_err(|e| redis_error("EVALSHA", e))?;
+        Ok(RedisValue::from_redis_value(&result, false))
+    }
+}
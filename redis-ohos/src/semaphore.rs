@@ -0,0 +1,198 @@
+// Distributed semaphore for HarmonyOS NAPI
+
+use napi_derive_ohos::napi;
+use napi_ohos::bindgen_prelude::*;
+use redis::{Connection, Script};
+use std::sync::LazyLock;
+use std::time::{Duration, Instant};
+
+use crate::error::redis_error;
+
+/// Try to take a slot: purge expired holders, then add a new one if below the limit
+///
+/// KEYS[1] = holders zset (score = expiry in ms), KEYS[2] = token sequence
+/// ARGV[1] = limit, ARGV[2] = holder TTL in ms
+/// Returns the new token, or false when all slots are taken
+static ACQUIRE_SCRIPT: LazyLock<Script> = LazyLock::new(|| {
+    Script::new(
+        r#"
+local now = redis.call('TIME')
+local now_ms = tonumber(now[1]) * 1000 + math.floor(tonumber(now[2]) / 1000)
+redis.call('ZREMRANGEBYSCORE', KEYS[1], '-inf', now_ms)
+if redis.call('ZCARD', KEYS[1]) >= tonumber(ARGV[1]) then
+  return false
+end
+local token = tostring(redis.call('INCR', KEYS[2]))
+redis.call('ZADD', KEYS[1], now_ms + tonumber(ARGV[2]), token)
+return token
+"#,
+    )
+});
+
+/// Extend a holder's lease if it hasn't expired yet
+///
+/// KEYS[1] = holders zset
+/// ARGV[1] = token, ARGV[2] = holder TTL in ms
+static REFRESH_SCRIPT: LazyLock<Script> = LazyLock::new(|| {
+    Script::new(
+        r#"
+local now = redis.call('TIME')
+local now_ms = tonumber(now[1]) * 1000 + math.floor(tonumber(now[2]) / 1000)
+local expiry = redis.call('ZSCORE', KEYS[1], ARGV[1])
+if not expiry or tonumber(expiry) <= now_ms then
+  redis.call('ZREM', KEYS[1], ARGV[1])
+  return 0
+end
+redis.call('ZADD', KEYS[1], now_ms + tonumber(ARGV[2]), ARGV[1])
+return 1
+"#,
+    )
+});
+
+/// Count holders whose lease hasn't expired
+///
+/// KEYS[1] = holders zset
+static COUNT_SCRIPT: LazyLock<Script> = LazyLock::new(|| {
+    Script::new(
+        r#"
+local now = redis.call('TIME')
+local now_ms = tonumber(now[1]) * 1000 + math.floor(tonumber(now[2]) / 1000)
+redis.call('ZREMRANGEBYSCORE', KEYS[1], '-inf', now_ms)
+return redis.call('ZCARD', KEYS[1])
+"#,
+    )
+});
+
+/// Distributed counting semaphore
+///
+/// Holders are stored in a sorted set scored by the expiry of their lease
+/// (server clock), so slots of crashed holders are reclaimed automatically
+/// once their TTL has passed. All operations are atomic Lua scripts.
+///
+/// # Example (ArkTS)
+/// ```typescript
+/// // At most 3 concurrent uploads across all devices, 30s leases
+/// const sem = client.getSemaphore("uploads", 3, 30000);
+///
+/// const token = sem.acquire(5000);
+/// if (token !== null) {
+///   try {
+///     upload();
+///   } finally {
+///     sem.release(token);
+///   }
+/// }
+/// ```
+#[napi]
+pub struct RedisSemaphore {
+    conn: Connection,
+    holders: String,
+    sequence: String,
+    limit: u32,
+    holder_ttl_ms: u32,
+}
+
+impl RedisSemaphore {
+    pub(crate) fn new(conn: Connection, name: &str, limit: u32, holder_ttl_ms: u32) -> Self {
+        RedisSemaphore {
+            conn,
+            holders: format!("{{{}}}:holders", name),
+            sequence: format!("{{{}}}:seq", name),
+            limit,
+            holder_ttl_ms,
+        }
+    }
+}
+
+#[napi]
+impl RedisSemaphore {
+    /// Try to acquire a slot without waiting
+    ///
+    /// # Returns
+    /// A holder token to pass to `release()` / `refresh()`, or null if all slots are taken
+    #[napi]
+    pub fn try_acquire(&mut self) -> Result<Option<String>> {
+        ACQUIRE_SCRIPT
+            .key(&self.holders)
+            .key(&self.sequence)
+            .arg(self.limit)
+            .arg(self.holder_ttl_ms)
+            .invoke(&mut self.conn)
+            .map_err(|e| redis_error("Semaphore acquire", e))
+    }
+
+    /// Acquire a slot, retrying until the timeout expires
+    ///
+    /// # Arguments
+    /// * `timeout_ms` - Maximum time to wait in milliseconds
+    /// * `retry_interval_ms` - Delay between attempts (default: 100)
+    ///
+    /// # Returns
+    /// A holder token, or null if no slot became free in time
+    ///
+    /// # Note
+    /// This blocks the calling thread while waiting.
+    #[napi]
+    pub fn acquire(&mut self, timeout_ms: u32, retry_interval_ms: Option<u32>) -> Result<Option<String>> {
+        let deadline = Instant::now() + Duration::from_millis(timeout_ms as u64);
+        let interval = Duration::from_millis(retry_interval_ms.unwrap_or(100) as u64);
+
+        loop {
+            if let Some(token) = self.try_acquire()? {
+                return Ok(Some(token));
+            }
+
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                return Ok(None);
+            }
+            std::thread::sleep(interval.min(remaining));
+        }
+    }
+
+    /// Release a slot
+    ///
+    /// # Returns
+    /// true if the token was still holding a slot
+    #[napi]
+    pub fn release(&mut self, token: String) -> Result<bool> {
+        let removed: i64 = redis::cmd("ZREM")
+            .arg(&self.holders)
+            .arg(token)
+            .query(&mut self.conn)
+            .map_err(|e| redis_error("Semaphore release", e))?;
+        Ok(removed > 0)
+    }
+
+    /// Extend the lease of a holder by the holder TTL
+    ///
+    /// Long-running holders should call this periodically.
+    ///
+    /// # Returns
+    /// false if the lease already expired and the slot was lost
+    #[napi]
+    pub fn refresh(&mut self, token: String) -> Result<bool> {
+        let refreshed: i64 = REFRESH_SCRIPT
+            .key(&self.holders)
+            .arg(token)
+            .arg(self.holder_ttl_ms)
+            .invoke(&mut self.conn)
+            .map_err(|e| redis_error("Semaphore refresh", e))?;
+        Ok(refreshed == 1)
+    }
+
+    /// Number of slots currently held
+    #[napi]
+    pub fn count(&mut self) -> Result<i64> {
+        COUNT_SCRIPT
+            .key(&self.holders)
+            .invoke(&mut self.conn)
+            .map_err(|e| redis_error("Semaphore count", e))
+    }
+
+    /// Maximum number of concurrent holders
+    #[napi(getter)]
+    pub fn limit(&self) -> u32 {
+        self.limit
+    }
+}
@@ -0,0 +1,285 @@
+// Redis Sentinel client for HarmonyOS NAPI
+//
+// Asks the sentinels for the address of the current master of a service and
+// connects to it. With `autoFailover`, connections ask again and reconnect
+// when their server goes away or answers READONLY after being demoted.
+//
+// Failovers can also be watched: a background thread subscribes to the
+// `+switch-master` channel of a sentinel and reports the master changes of
+// the service.
+
+use napi_derive_ohos::napi;
+use napi_ohos::bindgen_prelude::*;
+use napi_ohos::threadsafe_function::{ThreadsafeFunction, ThreadsafeFunctionCallMode};
+use redis::sentinel::{SentinelClient, SentinelNodeConnectionInfo, SentinelServerType};
+use redis::RedisConnectionInfo;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+use crate::connection::RedisConnection;
+use crate::error::{redis_error, report_background_error};
+use crate::metrics::Reconnect;
+use crate::types::{RedisSentinelConfig, SentinelFailoverEvent};
+
+/// Redis Sentinel client for HarmonyOS
+///
+/// # Example (ArkTS)
+/// ```typescript
+/// import { RedisSentinelClient } from 'libredis_ohos.so';
+///
+/// const sentinel = new RedisSentinelClient(["redis://10.0.0.1:26379", "redis://10.0.0.2:26379"], "mymaster");
+/// const conn = sentinel.getConnection(); // connected to the current master
+/// ```
+#[napi]
+pub struct RedisSentinelClient {
+    inner: Arc<Mutex<SentinelClient>>,
+    service_name: String,
+    db: i64,
+    auto_failover: bool,
+}
+
+impl RedisSentinelClient {
+    fn build(
+        sentinels: Vec<String>,
+        service_name: String,
+        info: SentinelNodeConnectionInfo,
+        db: i64,
+        auto_failover: bool,
+    ) -> Result<Self> {
+        let client = SentinelClient::build(sentinels, service_name.clone(), Some(info), SentinelServerType::Master)
+            .map_err(|e| redis_error("Sentinel client creation", e))?;
+        Ok(RedisSentinelClient {
+            inner: Arc::new(Mutex::new(client)),
+            service_name,
+            db,
+            auto_failover,
+        })
+    }
+
+    /// Resolve the current master and connect to it
+    fn connect(sentinel: &Mutex<SentinelClient>) -> redis::RedisResult<redis::Connection> {
+        sentinel.lock().unwrap_or_else(|e| e.into_inner()).get_connection()
+    }
+}
+
+#[napi]
+impl RedisSentinelClient {
+    /// Create a sentinel client
+    ///
+    /// # Arguments
+    /// * `sentinels` - URLs of one or more sentinel nodes (e.g., `redis://10.0.0.1:26379`)
+    /// * `service_name` - Name of the monitored master
+    #[napi(constructor)]
+    pub fn new(sentinels: Vec<String>, service_name: String) -> Result<Self> {
+        Self::build(sentinels, service_name, SentinelNodeConnectionInfo::default(), 0, false)
+    }
+
+    /// Create a sentinel client from a configuration object
+    ///
+    /// # Arguments
+    /// * `config` - Sentinel URLs, service name, master credentials and database,
+    ///   and whether connections follow failovers
+    #[napi(factory)]
+    pub fn from_config(config: RedisSentinelConfig) -> Result<Self> {
+        let db = config.db.unwrap_or(0) as i64;
+        let mut info = RedisConnectionInfo::default().set_db(db);
+        if let Some(username) = config.username {
+            info = info.set_username(username);
+        }
+        if let Some(password) = config.password {
+            info = info.set_password(password);
+        }
+
+        Self::build(
+            config.sentinels,
+            config.service_name,
+            SentinelNodeConnectionInfo::default().set_redis_connection_info(info),
+            db,
+            config.auto_failover.unwrap_or(false),
+        )
+    }
+
+    /// Address of the current master, as reported by the sentinels
+    ///
+    /// # Returns
+    /// The address as "host:port"
+    #[napi]
+    pub fn master_address(&self) -> Result<String> {
+        let client = self
+            .inner
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .get_client()
+            .map_err(|e| redis_error("Sentinel master lookup", e))?;
+        Ok(client.get_connection_info().addr().to_string())
+    }
+
+    /// Get a synchronous connection to the current master
+    ///
+    /// With `autoFailover`, the connection asks the sentinels for the master
+    /// again when its socket breaks or its server has been demoted to a
+    /// replica (READONLY), and reconnects. A command rejected with READONLY is
+    /// sent again to the new master; a command interrupted by a broken socket
+    /// still fails, since it may have been executed.
+    ///
+    /// `reconnect()` on the connection always connects to the current master.
+    ///
+    /// # Returns
+    /// A RedisConnection object
+    #[napi]
+    pub fn get_connection(&self) -> Result<RedisConnection> {
+        let conn = Self::connect(&self.inner).map_err(|e| redis_error("Connect", e))?;
+
+        let sentinel = self.inner.clone();
+        let reconnect: Reconnect = Arc::new(move || Self::connect(&sentinel));
+        Ok(RedisConnection::new(conn, self.db, None).with_reconnect(reconnect, self.auto_failover))
+    }
+
+    /// Watch failovers of the master
+    ///
+    /// Starts a background thread with its own connection to a sentinel,
+    /// subscribed to `+switch-master`.
+    ///
+    /// # Arguments
+    /// * `callback` - Called with each SentinelFailoverEvent on the ArkTS thread
+    ///
+    /// # Returns
+    /// A SentinelFailoverWatcher; call `stop()` to end the subscription
+    ///
+    /// # Example (ArkTS)
+    /// ```typescript
+    /// const watcher = sentinel.watchFailovers((e) => {
+    ///   console.log(`${e.serviceName}: ${e.oldMaster} -> ${e.newMaster}`);
+    ///   conn = sentinel.getConnection();
+    /// });
+    ///
+    /// // aboutToDisappear()
+    /// watcher.stop();
+    /// ```
+    #[napi]
+    pub fn watch_failovers(
+        &self,
+        callback: ThreadsafeFunction<SentinelFailoverEvent, Unknown<'static>, SentinelFailoverEvent, Status, false>,
+    ) -> Result<SentinelFailoverWatcher> {
+        let client = self
+            .inner
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .get_sentinel_client()
+            .map_err(|e| redis_error("Sentinel connect", e))?;
+        SentinelFailoverWatcher::start(&client, self.service_name.clone(), callback)
+    }
+}
+
+/// Parse a `+switch-master` message: `<name> <old-ip> <old-port> <new-ip> <new-port>`
+fn parse_switch_master(payload: &str) -> Option<SentinelFailoverEvent> {
+    let fields: Vec<&str> = payload.split_whitespace().collect();
+    let [name, old_host, old_port, new_host, new_port] = fields.as_slice() else {
+        return None;
+    };
+    Some(SentinelFailoverEvent {
+        service_name: name.to_string(),
+        old_master: format!("{}:{}", old_host, old_port),
+        new_master: format!("{}:{}", new_host, new_port),
+    })
+}
+
+/// Handle of a sentinel failover subscription
+///
+/// Created with `RedisSentinelClient.watchFailovers()`. Events stop when
+/// `stop()` is called or the watcher is garbage collected.
+#[napi]
+pub struct SentinelFailoverWatcher {
+    running: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl SentinelFailoverWatcher {
+    /// Subscribe and start the delivery thread
+    fn start(
+        client: &redis::Client,
+        service_name: String,
+        callback: ThreadsafeFunction<SentinelFailoverEvent, Unknown<'static>, SentinelFailoverEvent, Status, false>,
+    ) -> Result<Self> {
+        let mut conn = client.get_connection().map_err(|e| redis_error("Sentinel connect", e))?;
+
+        let running = Arc::new(AtomicBool::new(true));
+        let flag = running.clone();
+        // The subscription lives inside the thread (dropping PubSub unsubscribes);
+        // its outcome is reported back before start() returns
+        let (ready_tx, ready_rx) = std::sync::mpsc::channel();
+
+        let handle = std::thread::spawn(move || {
+            let mut pubsub = conn.as_pubsub();
+            let subscribed = pubsub
+                .subscribe("+switch-master")
+                .and_then(|_| pubsub.set_read_timeout(Some(Duration::from_millis(500))));
+            let failed = subscribed.is_err();
+            let _ = ready_tx.send(subscribed);
+            if failed {
+                return;
+            }
+
+            while flag.load(Ordering::Acquire) {
+                let msg = match pubsub.get_message() {
+                    Ok(msg) => msg,
+                    Err(e) if e.is_timeout() => continue,
+                    Err(e) => {
+                        report_background_error("Sentinel failover watcher", &redis_error("+switch-master", e));
+                        break;
+                    }
+                };
+
+                let payload = String::from_utf8_lossy(msg.get_payload_bytes());
+                if let Some(event) = parse_switch_master(&payload).filter(|e| e.service_name == service_name) {
+                    callback.call(event, ThreadsafeFunctionCallMode::NonBlocking);
+                }
+            }
+        });
+
+        match ready_rx.recv() {
+            Ok(Ok(())) => {}
+            Ok(Err(e)) => {
+                let _ = handle.join();
+                return Err(redis_error("SUBSCRIBE", e));
+            }
+            Err(_) => {
+                let _ = handle.join();
+                return Err(napi_ohos::Error::from_reason("Sentinel failover watcher failed to start"));
+            }
+        }
+
+        Ok(SentinelFailoverWatcher {
+            running,
+            handle: Some(handle),
+        })
+    }
+}
+
+#[napi]
+impl SentinelFailoverWatcher {
+    /// Stop delivering events
+    ///
+    /// Waits for the subscriber thread to exit.
+    #[napi]
+    pub fn stop(&mut self) {
+        if let Some(handle) = self.handle.take() {
+            self.running.store(false, Ordering::Release);
+            let _ = handle.join();
+        }
+    }
+
+    /// Whether events are still being delivered
+    #[napi(getter)]
+    pub fn is_running(&self) -> bool {
+        self.handle.as_ref().is_some_and(|handle| !handle.is_finished())
+    }
+}
+
+impl Drop for SentinelFailoverWatcher {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
@@ -0,0 +1,237 @@
+// Stream consumer group worker for HarmonyOS NAPI
+//
+// A background thread loops XREADGROUP on its own connection and hands every
+// entry to an ArkTS callback. Entries are acknowledged once the callback
+// returns; if it throws, the entry stays pending and can be redelivered or
+// claimed by another consumer.
+//
+// Pending entries are replayed in a single pass when the worker starts, so
+// an entry the callback keeps rejecting cannot hold up the stream.
+
+use napi_derive_ohos::napi;
+use napi_ohos::bindgen_prelude::*;
+use napi_ohos::threadsafe_function::{ThreadsafeFunction, ThreadsafeFunctionCallMode};
+use redis::Connection;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc};
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+use crate::client::RedisClient;
+use crate::connection::{xread_cmd, StreamReadReply};
+use crate::error::{redis_error, report_background_error};
+use crate::types::{StreamConsumerOptions, StreamEntry};
+
+/// Extra time allowed for an XREADGROUP reply past its BLOCK duration
+const READ_GRACE: Duration = Duration::from_secs(1);
+
+/// How often a worker waiting on the callback checks for `stop()`
+const CALLBACK_POLL: Duration = Duration::from_millis(200);
+
+/// Pause after the first failed entry; doubled after each further failure in a row
+const FAILURE_BACKOFF: Duration = Duration::from_millis(100);
+
+/// Longest pause after failed entries
+const MAX_FAILURE_BACKOFF: Duration = Duration::from_secs(5);
+
+/// Pause after `failures` callbacks in a row have thrown
+fn failure_backoff(failures: u32) -> Duration {
+    FAILURE_BACKOFF
+        .saturating_mul(1 << failures.saturating_sub(1).min(16))
+        .min(MAX_FAILURE_BACKOFF)
+}
+
+/// Sleep for `delay` unless the worker is stopped first
+///
+/// Returns false when the worker was stopped.
+fn pause(running: &AtomicBool, delay: Duration) -> bool {
+    let deadline = std::time::Instant::now() + delay;
+    while running.load(Ordering::Acquire) {
+        let left = deadline.saturating_duration_since(std::time::Instant::now());
+        if left.is_zero() {
+            return true;
+        }
+        std::thread::sleep(left.min(CALLBACK_POLL));
+    }
+    false
+}
+
+/// Create the consumer group, tolerating one that already exists
+fn create_group(conn: &mut Connection, key: &str, group: &str, start_id: &str) -> Result<()> {
+    let created = redis::cmd("XGROUP")
+        .arg("CREATE")
+        .arg(key)
+        .arg(group)
+        .arg(start_id)
+        .arg("MKSTREAM")
+        .query::<()>(conn);
+    match created {
+        Err(e) if e.code() == Some("BUSYGROUP") => Ok(()),
+        other => other.map_err(|e| redis_error("XGROUP CREATE", e)),
+    }
+}
+
+/// Consumer group member processing a stream in the background
+///
+/// Created with `RedisClient.streamConsumer()`. Entries are delivered one at
+/// a time, in order; the entry is acknowledged (XACK) when the callback
+/// returns and left pending when it throws. After a throw the worker waits
+/// before the next entry, from 100 ms up to 5 s while failures continue.
+///
+/// Entries left pending are redelivered once, the next time a consumer with
+/// the same name starts with `recoverPending`.
+///
+/// # Example (ArkTS)
+/// ```typescript
+/// const consumer = client.streamConsumer("orders", "billing", "device-42", (entry) => {
+///   charge(entry.fields["orderId"], Number(entry.fields["amount"]));
+/// });
+///
+/// // aboutToDisappear()
+/// consumer.stop();
+/// ```
+#[napi]
+pub struct RedisStreamConsumer {
+    running: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl RedisStreamConsumer {
+    /// Prepare the group and start the worker thread
+    pub(crate) fn start(
+        client: &RedisClient,
+        key: String,
+        group: String,
+        consumer: String,
+        callback: ThreadsafeFunction<StreamEntry, Unknown<'static>, StreamEntry, Status, false>,
+        options: Option<StreamConsumerOptions>,
+    ) -> Result<Self> {
+        let options = options.unwrap_or_default();
+        let count = options.count.unwrap_or(10).max(1) as i32;
+        let block_ms = options.block_ms.unwrap_or(2000).max(1);
+
+        let mut conn = client.connect(None).map_err(|e| redis_error("Connect", e))?;
+        if options.create_group.unwrap_or(true) {
+            let start_id = options.start_id.as_deref().unwrap_or("$");
+            create_group(&mut conn, &key, &group, start_id)?;
+        }
+        conn.set_read_timeout(Some(Duration::from_millis(block_ms as u64) + READ_GRACE))
+            .map_err(|e| redis_error("Connect", e))?;
+
+        let running = Arc::new(AtomicBool::new(true));
+        let flag = running.clone();
+        let keys = [key];
+
+        let handle = std::thread::spawn(move || {
+            // While recovering, pending entries are read from after the last one
+            // delivered, so each is replayed once; then ">" reads new entries
+            let mut pending_from = options.recover_pending.unwrap_or(true).then(|| "0".to_string());
+            let mut failures = 0u32;
+
+            while flag.load(Ordering::Acquire) {
+                let ids = [pending_from.clone().unwrap_or_else(|| ">".to_string())];
+                let read = xread_cmd(Some((&group, &consumer)), &keys, &ids, Some(count), Some(i64::from(block_ms)))
+                    .and_then(|cmd| {
+                        cmd.query::<Option<StreamReadReply>>(&mut conn)
+                            .map_err(|e| redis_error("XREADGROUP", e))
+                    });
+                let entries: Vec<StreamEntry> = match read {
+                    Ok(reply) => reply
+                        .into_iter()
+                        .flatten()
+                        .flat_map(|(_, entries)| entries)
+                        .map(StreamEntry::from)
+                        .collect(),
+                    Err(e) => {
+                        report_background_error("Stream consumer", &e);
+                        break;
+                    }
+                };
+
+                if entries.is_empty() {
+                    pending_from = None;
+                    continue;
+                }
+
+                for entry in entries {
+                    let id = entry.id.clone();
+                    if pending_from.is_some() {
+                        pending_from = Some(id.clone());
+                    }
+                    let (done_tx, done_rx) = mpsc::channel();
+                    callback.call_with_return_value(
+                        entry,
+                        ThreadsafeFunctionCallMode::NonBlocking,
+                        move |result: Result<Unknown<'_>>, _env| {
+                            let _ = done_tx.send(result.is_ok());
+                            Ok(())
+                        },
+                    );
+
+                    let succeeded = loop {
+                        match done_rx.recv_timeout(CALLBACK_POLL) {
+                            Ok(succeeded) => break Some(succeeded),
+                            Err(mpsc::RecvTimeoutError::Timeout) if flag.load(Ordering::Acquire) => continue,
+                            // Stopped, or the callback was released: leave the entry pending
+                            Err(_) => break None,
+                        }
+                    };
+
+                    match succeeded {
+                        Some(true) => {
+                            let acked = redis::cmd("XACK")
+                                .arg(&keys[0])
+                                .arg(&group)
+                                .arg(&id)
+                                .query::<i64>(&mut conn);
+                            if let Err(e) = acked {
+                                report_background_error("Stream consumer", &redis_error("XACK", e));
+                            }
+                            failures = 0;
+                        }
+                        Some(false) => {
+                            failures = failures.saturating_add(1);
+                            if !pause(&flag, failure_backoff(failures)) {
+                                return;
+                            }
+                        }
+                        None => return,
+                    }
+                }
+            }
+        });
+
+        Ok(RedisStreamConsumer {
+            running,
+            handle: Some(handle),
+        })
+    }
+}
+
+#[napi]
+impl RedisStreamConsumer {
+    /// Stop consuming
+    ///
+    /// Waits for the current XREADGROUP to return (at most `blockMs`).
+    /// Entries fetched but not yet handed to the callback stay pending and
+    /// are redelivered when the consumer starts again.
+    #[napi]
+    pub fn stop(&mut self) {
+        if let Some(handle) = self.handle.take() {
+            self.running.store(false, Ordering::Release);
+            let _ = handle.join();
+        }
+    }
+
+    /// Whether entries are still being consumed
+    #[napi(getter)]
+    pub fn is_running(&self) -> bool {
+        self.handle.as_ref().is_some_and(|handle| !handle.is_finished())
+    }
+}
+
+impl Drop for RedisStreamConsumer {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
@@ -1,6 +1,7 @@
 // Redis types for HarmonyOS NAPI
 
 use napi_derive_ohos::napi;
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
 
 /// Redis Client Configuration
 ///
@@ -52,8 +53,159 @@ pub struct RedisClientConfig {
     /// If true, uses rediss:// protocol
     pub use_tls: Option<bool>,
 
-    /// Connection timeout in milliseconds (default: no timeout)
+    /// Accept any server certificate without verifying it (default: false).
+    /// Only for testing against self-signed servers; requires `use_tls`
+    pub tls_insecure: Option<bool>,
+
+    /// PEM-encoded CA certificate to verify the server with, instead of the
+    /// system trust store; requires `use_tls`
+    pub ca_cert_pem: Option<String>,
+
+    /// PEM-encoded client certificate for mutual TLS; requires `client_key_pem`
+    pub client_cert_pem: Option<String>,
+
+    /// PEM-encoded private key of `client_cert_pem`
+    pub client_key_pem: Option<String>,
+
+    /// Deprecated alias of `connect_timeout_ms`
     pub timeout_ms: Option<u32>,
+
+    /// How long connecting may take, in milliseconds (default: no timeout for
+    /// sync connections, 1000 for async connections)
+    pub connect_timeout_ms: Option<u32>,
+
+    /// How long commands wait for the server, in milliseconds. Sync
+    /// connections apply it to socket reads and writes (default: no timeout
+    /// for sync connections, 500 for async connections)
+    pub response_timeout_ms: Option<u32>,
+
+    /// RESP protocol version, 2 or 3 (default: 2). RESP3 connections
+    /// negotiate it with HELLO 3 and return maps, doubles, big numbers and
+    /// attributes as such; requires Redis 6 or later
+    pub protocol: Option<u32>,
+
+    /// Retry commands rejected with LOADING, BUSY, MASTERDOWN or CLUSTERDOWN
+    /// (default: fail immediately)
+    pub transient_retry: Option<TransientRetryConfig>,
+
+    /// Reconnect and resend commands that failed with a broken connection or
+    /// a timeout (default: fail immediately)
+    pub connection_retry: Option<ConnectionRetryConfig>,
+
+    /// Shorthand enabling both `transient_retry` and `connection_retry` with
+    /// this many retries per command; fields set in those objects take
+    /// precedence. Commands that are not idempotent (INCR, LPUSH, XADD...) are
+    /// still not resent after a connection failure unless
+    /// `connectionRetry.retryNonIdempotent` is set.
+    pub max_retries: Option<u32>,
+
+    /// Delay before the first retry in milliseconds, for both retry policies
+    pub retry_base_delay_ms: Option<u32>,
+
+    /// Upper bound of the retry delay in milliseconds, for both retry policies
+    pub retry_max_delay_ms: Option<u32>,
+
+    /// Enable TCP keepalive probes once the socket has been idle for this
+    /// many milliseconds (default: system setting, usually disabled)
+    pub tcp_keepalive_ms: Option<u32>,
+
+    /// Send a PING on sync connections left idle for this many milliseconds,
+    /// so NAT and firewall idle timeouts don't drop them (default: disabled)
+    pub heartbeat_interval_ms: Option<u32>,
+
+    /// Return from `getConnection()` without connecting; the socket is opened
+    /// by the first command, so clients can be set up before the network is
+    /// available (default: false)
+    pub lazy_connect: Option<bool>,
+
+    /// Name given to sync connections with CLIENT SETNAME, shown by CLIENT
+    /// LIST on the server; this includes the connections of queues,
+    /// trackers, watchers and other background workers (default: unnamed)
+    pub client_name: Option<String>,
+
+    /// Address family to connect over (default: "any", in resolver order).
+    /// Applies to async connections and to sync plain-TCP connections,
+    /// including those of queues, trackers, watchers and other background
+    /// workers. Sync TLS connections ignore it and connect in system
+    /// resolver order, since their socket is opened by the TLS layer
+    pub ip_family: Option<IpFamily>,
+
+    /// IP address to connect to instead of resolving `host`, e.g. one the
+    /// app resolved itself. TLS still verifies the certificate for `host`
+    /// on async connections. Like `ipFamily`, it is ignored by sync TLS
+    /// connections
+    pub resolved_address: Option<String>,
+
+    /// Follow cluster MOVED/ASK redirections on sync connections, up to this
+    /// many hops per command (default: redirections are returned as errors)
+    pub max_redirects: Option<u32>,
+}
+
+/// Retry budget for transient server errors
+///
+/// Commands answered with LOADING, BUSY, MASTERDOWN or CLUSTERDOWN were not
+/// executed, so they are safe to send again. The delay doubles after every
+/// attempt, up to `max_delay_ms`. Pipelines are never retried.
+///
+/// # Example (ArkTS)
+/// ```typescript
+/// const client = RedisClient.fromConfig({
+///   host: "10.0.0.5",
+///   transientRetry: { maxAttempts: 10, initialDelayMs: 200 }
+/// });
+/// ```
+#[napi(object)]
+#[derive(Debug, Clone, Default)]
+pub struct TransientRetryConfig {
+    /// Maximum number of retries per command (default: 5)
+    pub max_attempts: Option<u32>,
+
+    /// Delay before the first retry in milliseconds (default: 100)
+    pub initial_delay_ms: Option<u32>,
+
+    /// Upper bound of the delay in milliseconds (default: 2000)
+    pub max_delay_ms: Option<u32>,
+}
+
+/// Retry budget for connection failures
+///
+/// When a command fails because the socket broke or timed out, the
+/// connection is reopened and the command sent again. The delay doubles after
+/// every attempt, up to `max_delay_ms`. Pipelines and transactions are never
+/// resent.
+///
+/// A command that failed this way may still have been executed by the
+/// server, so commands such as INCR, LPUSH, XADD or SET with NX or GET,
+/// which would then be applied twice or reply differently, fail instead of
+/// being resent. Set `retryNonIdempotent` only
+/// when duplicate writes are acceptable.
+///
+/// # Example (ArkTS)
+/// ```typescript
+/// const client = RedisClient.fromConfig({
+///   host: "10.0.0.5",
+///   connectionRetry: { maxAttempts: 3 }
+/// });
+/// ```
+#[napi(object)]
+#[derive(Debug, Clone, Default)]
+pub struct ConnectionRetryConfig {
+    /// Maximum number of retries per command (default: 3)
+    pub max_attempts: Option<u32>,
+
+    /// Delay before the first retry in milliseconds (default: 100)
+    pub initial_delay_ms: Option<u32>,
+
+    /// Upper bound of the delay in milliseconds (default: 2000)
+    pub max_delay_ms: Option<u32>,
+
+    /// Wait a random time between half and all of each delay, so that many
+    /// clients don't reconnect at the same instant (default: true)
+    pub jitter: Option<bool>,
+
+    /// Also resend commands that are not idempotent, at the risk of applying
+    /// them twice (default: false)
+    pub retry_non_idempotent: Option<bool>,
 }
 
 impl Default for RedisClientConfig {
@@ -65,7 +217,26 @@ impl Default for RedisClientConfig {
             username: None,
             password: None,
             use_tls: Some(false),
+            tls_insecure: None,
+            ca_cert_pem: None,
+            client_cert_pem: None,
+            client_key_pem: None,
             timeout_ms: None,
+            connect_timeout_ms: None,
+            response_timeout_ms: None,
+            protocol: None,
+            transient_retry: None,
+            connection_retry: None,
+            max_retries: None,
+            retry_base_delay_ms: None,
+            retry_max_delay_ms: None,
+            tcp_keepalive_ms: None,
+            heartbeat_interval_ms: None,
+            lazy_connect: None,
+            client_name: None,
+            ip_family: None,
+            resolved_address: None,
+            max_redirects: None,
         }
     }
 }
@@ -80,14 +251,51 @@ impl RedisClientConfig {
 
         let protocol = if use_tls { "rediss" } else { "redis" };
 
-        // Build auth part
+        // Build auth part; credentials are percent-encoded so that characters
+        // such as '@', ':', '/' or '#' don't end up in the URL syntax
+        let encode = |s: &str| utf8_percent_encode(s, NON_ALPHANUMERIC).to_string();
         let auth = match (&self.username, &self.password) {
-            (Some(username), Some(password)) => format!("{}:{}@", username, password),
-            (None, Some(password)) => format!(":{}@", password),
+            (Some(username), Some(password)) => format!("{}:{}@", encode(username), encode(password)),
+            (None, Some(password)) => format!(":{}@", encode(password)),
             _ => String::new(),
         };
 
-        format!("{}://{}{}:{}/{}", protocol, auth, host, port, db)
+        // Certificate verification is switched off through the URL fragment
+        let fragment = if use_tls && self.tls_insecure.unwrap_or(false) { "#insecure" } else { "" };
+
+        format!("{}://{}{}:{}/{}{}", protocol, auth, host, port, db, fragment)
+    }
+
+    /// Whether any of the retry shorthand fields is set
+    fn has_retry_settings(&self) -> bool {
+        self.max_retries.is_some() || self.retry_base_delay_ms.is_some() || self.retry_max_delay_ms.is_some()
+    }
+
+    /// `transient_retry` completed with the retry shorthand fields
+    pub(crate) fn transient_retry_config(&self) -> Option<TransientRetryConfig> {
+        if self.transient_retry.is_none() && !self.has_retry_settings() {
+            return None;
+        }
+        let config = self.transient_retry.clone().unwrap_or_default();
+        Some(TransientRetryConfig {
+            max_attempts: config.max_attempts.or(self.max_retries),
+            initial_delay_ms: config.initial_delay_ms.or(self.retry_base_delay_ms),
+            max_delay_ms: config.max_delay_ms.or(self.retry_max_delay_ms),
+        })
+    }
+
+    /// `connection_retry` completed with the retry shorthand fields
+    pub(crate) fn connection_retry_config(&self) -> Option<ConnectionRetryConfig> {
+        if self.connection_retry.is_none() && !self.has_retry_settings() {
+            return None;
+        }
+        let config = self.connection_retry.clone().unwrap_or_default();
+        Some(ConnectionRetryConfig {
+            max_attempts: config.max_attempts.or(self.max_retries),
+            initial_delay_ms: config.initial_delay_ms.or(self.retry_base_delay_ms),
+            max_delay_ms: config.max_delay_ms.or(self.retry_max_delay_ms),
+            ..config
+        })
     }
 }
 
@@ -95,6 +303,7 @@ impl RedisClientConfig {
 ///
 /// Represents the different types of values that can be stored in Redis.
 #[napi]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum RedisValueType {
     /// String type
     String,
@@ -134,6 +343,7 @@ pub enum RedisExpireOption {
 ///
 /// Represents the result of setting expiration on a hash field.
 #[napi]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum RedisExpireResult {
     /// The expiration was successfully set (value: 1)
     Success,
@@ -336,3 +546,1576 @@ impl RedisInfo {
     }
 }
 
+
+/// Options for memory analysis by key pattern
+///
+/// Either `patterns` or `delimiter` controls how keys are grouped. When
+/// `patterns` is provided every pattern becomes its own group; otherwise keys
+/// are grouped by the prefix before the first occurrence of `delimiter`.
+///
+/// # Example (ArkTS)
+/// ```typescript
+/// // Group by explicit patterns
+/// const byPattern = conn.analyzeMemoryByPattern({ patterns: ["user:*", "session:*"] });
+///
+/// // Group by the first segment of the key name
+/// const byPrefix = conn.analyzeMemoryByPattern({ delimiter: ":" });
+/// ```
+#[napi(object)]
+#[derive(Debug, Clone, Default)]
+pub struct MemoryAnalysisOptions {
+    /// Glob patterns to group by (e.g. ["user:*", "session:*"])
+    pub patterns: Option<Vec<String>>,
+
+    /// Namespace delimiter used when no patterns are given (default: ":")
+    pub delimiter: Option<String>,
+
+    /// SCAN COUNT hint per iteration (default: 1000)
+    pub scan_count: Option<u32>,
+
+    /// MEMORY USAGE SAMPLES value for nested types (default: server default)
+    pub samples: Option<u32>,
+}
+
+/// Memory usage aggregated for one key namespace
+///
+/// Returned by `analyzeMemoryByPattern()`, sorted by `totalBytes` descending.
+#[napi(object)]
+#[derive(Debug, Clone, Default)]
+pub struct NamespaceMemoryStats {
+    /// The pattern or prefix this group represents
+    pub namespace: String,
+
+    /// Number of keys in the group
+    pub key_count: i64,
+
+    /// Sum of MEMORY USAGE for all keys in bytes
+    pub total_bytes: i64,
+
+    /// Average bytes per key
+    pub avg_bytes: i64,
+
+    /// Size of the largest key in bytes
+    pub max_bytes: i64,
+
+    /// Name of the largest key
+    pub largest_key: Option<String>,
+}
+
+/// Hot key sampling strategy
+#[napi]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HotKeySampleMode {
+    /// Read the LFU counter of scanned keys with OBJECT FREQ
+    /// (requires an `allkeys-lfu` or `volatile-lfu` maxmemory-policy)
+    Lfu,
+    /// Count key accesses observed through MONITOR for a period of time
+    Monitor,
+}
+
+/// Options for hot key sampling
+///
+/// # Example (ArkTS)
+/// ```typescript
+/// import { HotKeySampleMode } from 'libredis_ohos.so';
+///
+/// // LFU based sampling of up to 50000 keys
+/// const hot = client.sampleHotKeys({ mode: HotKeySampleMode.Lfu, maxKeys: 50000 });
+///
+/// // Observe live traffic for 5 seconds
+/// const live = client.sampleHotKeys({ mode: HotKeySampleMode.Monitor, durationMs: 5000 });
+/// ```
+#[napi(object)]
+#[derive(Debug, Clone, Default)]
+pub struct HotKeySampleOptions {
+    /// Sampling strategy (default: Lfu)
+    pub mode: Option<HotKeySampleMode>,
+
+    /// Number of hot keys to return (default: 20)
+    pub top: Option<u32>,
+
+    /// Lfu mode: pattern of keys to inspect (default: "*")
+    pub pattern: Option<String>,
+
+    /// Lfu mode: maximum number of keys to inspect (default: 10000)
+    pub max_keys: Option<u32>,
+
+    /// Lfu mode: SCAN COUNT hint per iteration (default: 1000)
+    pub scan_count: Option<u32>,
+
+    /// Monitor mode: how long to observe traffic in milliseconds (default: 1000)
+    pub duration_ms: Option<u32>,
+
+    /// Monitor mode: stop after this many observed commands (default: unlimited)
+    pub max_commands: Option<u32>,
+}
+
+/// A frequently accessed key
+#[napi(object)]
+#[derive(Debug, Clone)]
+pub struct HotKeyInfo {
+    /// The key name
+    pub key: String,
+
+    /// Lfu mode: logarithmic access frequency counter (0-255)
+    /// Monitor mode: number of observed commands touching the key
+    pub hits: i64,
+}
+
+/// File format used by `exportKeys()`
+#[napi]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum KeyExportFormat {
+    /// Compact binary format
+    Dump,
+    /// JSON Lines with base64-encoded DUMP payloads
+    Json,
+}
+
+/// Options for `exportKeys()`
+#[napi(object)]
+#[derive(Debug, Clone, Default)]
+pub struct KeyExportOptions {
+    /// File format (default: Dump)
+    pub format: Option<KeyExportFormat>,
+
+    /// SCAN COUNT hint per iteration (default: 1000)
+    pub scan_count: Option<u32>,
+}
+
+/// Options for `importKeys()`
+#[napi(object)]
+#[derive(Debug, Clone, Default)]
+pub struct KeyImportOptions {
+    /// Overwrite keys that already exist (default: false, existing keys are skipped)
+    pub replace: Option<bool>,
+
+    /// Number of RESTORE commands sent per round trip (default: 500)
+    pub batch_size: Option<u32>,
+}
+
+/// Result of `importKeys()`
+#[napi(object)]
+#[derive(Debug, Clone, Default)]
+pub struct KeyImportResult {
+    /// Number of keys restored
+    pub imported: i64,
+
+    /// Number of keys skipped because they already exist (replace disabled)
+    pub skipped: i64,
+
+    /// Number of keys that failed to restore
+    pub failed: i64,
+
+    /// Error messages for failed keys (at most 100 are kept)
+    pub errors: Vec<String>,
+}
+
+/// What to do when a migrated key already exists on the target
+#[napi]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MigrationConflictPolicy {
+    /// Keep the target key and skip the source key
+    Skip,
+    /// Overwrite the target key
+    Replace,
+    /// Abort the migration with an error
+    Fail,
+}
+
+/// Options for `migrateKeys()`
+#[napi(object)]
+#[derive(Debug, Clone, Default)]
+pub struct MigrationOptions {
+    /// Conflict policy for keys that already exist on the target (default: Skip)
+    pub conflict: Option<MigrationConflictPolicy>,
+
+    /// Delete keys from the source after they were copied (default: false)
+    pub delete_source: Option<bool>,
+
+    /// SCAN COUNT hint, also the number of keys copied per batch (default: 1000)
+    pub scan_count: Option<u32>,
+}
+
+/// Progress and result of `migrateKeys()`
+#[napi(object)]
+#[derive(Debug, Clone, Default)]
+pub struct MigrationReport {
+    /// Number of source keys seen so far
+    pub scanned: i64,
+
+    /// Number of keys copied to the target
+    pub migrated: i64,
+
+    /// Number of keys skipped because they already exist on the target
+    pub skipped: i64,
+
+    /// Number of keys that failed to copy
+    pub failed: i64,
+
+    /// Error messages for failed keys (at most 100 are kept)
+    pub errors: Vec<String>,
+}
+
+/// Options for `exportToCsv()` / `exportToJson()`
+#[napi(object)]
+#[derive(Debug, Clone, Default)]
+pub struct DatasetExportOptions {
+    /// SCAN COUNT hint per iteration (default: 1000)
+    pub scan_count: Option<u32>,
+
+    /// Maximum number of elements rendered per list/set/zset/hash/stream (default: unlimited)
+    ///
+    /// 0 renders empty collections. Sets and hashes are sampled with a single
+    /// SSCAN/HSCAN page, so they may render fewer elements than the limit.
+    pub max_items: Option<u32>,
+}
+
+/// A job popped from a `RedisDelayedQueue`
+#[napi(object)]
+#[derive(Debug, Clone)]
+pub struct DelayedJob {
+    /// Job id returned by `schedule()` / `scheduleAt()`
+    pub id: String,
+
+    /// Job payload
+    pub payload: String,
+}
+
+/// Options for `RedisDelayedQueue.start()`
+#[napi(object)]
+#[derive(Debug, Clone, Default)]
+pub struct DelayedQueueWorkerOptions {
+    /// Delay between polls when no job is due, in milliseconds (default: 1000)
+    pub poll_interval_ms: Option<u32>,
+
+    /// Maximum number of jobs popped per poll, at least 1 (default: 100)
+    pub batch_size: Option<u32>,
+}
+
+/// A key with its metadata, as listed by `browseKeys()`
+#[napi(object)]
+#[derive(Debug, Clone)]
+pub struct KeyBrowseItem {
+    /// The key name
+    pub key: String,
+
+    /// The key type
+    #[napi(js_name = "type")]
+    pub key_type: RedisValueType,
+
+    /// Remaining time to live in seconds, or -1 for no expiration
+    pub ttl: i64,
+
+    /// Memory usage in bytes as reported by MEMORY USAGE
+    pub bytes: Option<i64>,
+}
+
+/// One page of `browseKeys()` results
+#[napi(object)]
+#[derive(Debug, Clone)]
+pub struct KeyBrowsePage {
+    /// Cursor for the next page, "0" when the iteration is complete
+    pub cursor: String,
+
+    /// Keys in this page
+    pub items: Vec<KeyBrowseItem>,
+}
+
+/// Options for `inspectKey()`
+#[napi(object)]
+#[derive(Debug, Clone, Default)]
+pub struct InspectKeyOptions {
+    /// Maximum number of elements previewed for lists, sets, sorted sets, hashes and streams (default: 100)
+    pub preview_limit: Option<u32>,
+
+    /// Maximum number of bytes previewed for strings (default: 4096)
+    pub string_limit: Option<u32>,
+}
+
+/// Type-tagged preview of a key, as returned by `inspectKey()`
+///
+/// The shape of `value` depends on `type`:
+/// - String: the (possibly truncated) string
+/// - List / Set: array of elements
+/// - ZSet: array of `{ member, score }`
+/// - Hash: object mapping fields to values
+/// - Stream: array of `{ id, fields }`
+#[napi(object)]
+#[derive(Debug, Clone)]
+pub struct KeyInspection {
+    /// The key name
+    pub key: String,
+
+    /// The key type, which tags the shape of `value`
+    #[napi(js_name = "type")]
+    pub key_type: RedisValueType,
+
+    /// Remaining time to live in seconds, or -1 for no expiration
+    pub ttl: i64,
+
+    /// Full size of the value: bytes for strings, element count otherwise
+    pub length: i64,
+
+    /// Whether `value` holds only part of the data
+    pub truncated: bool,
+
+    /// Bounded preview of the value
+    pub value: serde_json::Value,
+}
+
+/// Options for `buildKeyTree()`
+#[napi(object)]
+#[derive(Debug, Clone, Default)]
+pub struct KeyTreeOptions {
+    /// Maximum tree depth below the root; deeper keys are counted at the last level (default: unlimited)
+    pub max_depth: Option<u32>,
+
+    /// Pattern of keys to include (default: "*")
+    pub pattern: Option<String>,
+
+    /// Also aggregate MEMORY USAGE per node (default: false)
+    pub include_sizes: Option<bool>,
+
+    /// SCAN COUNT hint per iteration (default: 1000)
+    pub scan_count: Option<u32>,
+}
+
+/// A node of the namespace tree built by `buildKeyTree()`
+#[napi(object)]
+#[derive(Debug, Clone, Default)]
+pub struct KeyTreeNode {
+    /// Segment name of this node (empty for the root)
+    pub name: String,
+
+    /// Full prefix of this node, segments joined by the delimiter
+    pub path: String,
+
+    /// Number of keys in this subtree
+    pub key_count: i64,
+
+    /// Whether a key with exactly this path exists
+    pub is_key: bool,
+
+    /// Total memory usage of the subtree in bytes (only with `includeSizes`)
+    pub bytes: Option<i64>,
+
+    /// Child nodes sorted by name
+    pub children: Vec<KeyTreeNode>,
+}
+
+/// Options for `renameByPattern()`
+#[napi(object)]
+#[derive(Debug, Clone, Default)]
+pub struct RenameOptions {
+    /// Only report what would be renamed, without changing anything (default: false)
+    pub dry_run: Option<bool>,
+
+    /// Overwrite existing target keys with RENAME instead of skipping them with RENAMENX (default: false)
+    pub overwrite: Option<bool>,
+
+    /// SCAN COUNT hint, also the number of keys renamed per batch (default: 1000)
+    pub scan_count: Option<u32>,
+}
+
+/// Result of `renameByPattern()`
+#[napi(object)]
+#[derive(Debug, Clone, Default)]
+pub struct RenameReport {
+    /// Number of keys matching the source prefix
+    pub scanned: i64,
+
+    /// Number of keys renamed (or that would be renamed in a dry run)
+    pub renamed: i64,
+
+    /// Number of keys skipped because the target name already exists
+    pub skipped: i64,
+
+    /// Number of keys that failed to rename
+    pub failed: i64,
+
+    /// Source keys whose target name already exists (at most 1000 are kept)
+    pub conflicts: Vec<String>,
+
+    /// Error messages for failed keys (at most 100 are kept)
+    pub errors: Vec<String>,
+}
+
+/// Options for `copyKeysToDb()`
+#[napi(object)]
+#[derive(Debug, Clone, Default)]
+pub struct CopyKeysOptions {
+    /// Overwrite keys that already exist in the target database (default: false)
+    pub replace: Option<bool>,
+
+    /// SCAN COUNT hint, also the number of keys copied per batch (default: 1000)
+    pub scan_count: Option<u32>,
+}
+
+/// Result of `copyKeysToDb()`
+#[napi(object)]
+#[derive(Debug, Clone, Default)]
+pub struct CopyKeysReport {
+    /// Number of source keys seen
+    pub scanned: i64,
+
+    /// Number of keys copied to the target database
+    pub copied: i64,
+
+    /// Number of keys skipped because they already exist in the target database
+    pub skipped: i64,
+
+    /// Number of keys that failed to copy
+    pub failed: i64,
+
+    /// Error messages for failed keys (at most 100 are kept)
+    pub errors: Vec<String>,
+}
+
+/// How `diffDatabases()` compares values
+#[napi]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DiffMode {
+    /// Compare DUMP payloads (fast, but servers with different versions or
+    /// internal encodings can report equal values as different)
+    Dump,
+    /// Compare the values themselves, ignoring the order of set members and hash fields
+    Value,
+}
+
+/// Options for `diffDatabases()`
+#[napi(object)]
+#[derive(Debug, Clone, Default)]
+pub struct DiffOptions {
+    /// Comparison mode (default: Dump)
+    pub mode: Option<DiffMode>,
+
+    /// Also look for keys that only exist on the other side (default: true)
+    pub check_extra: Option<bool>,
+
+    /// SCAN COUNT hint per iteration (default: 1000)
+    pub scan_count: Option<u32>,
+
+    /// Maximum number of keys kept in each result list (default: 1000)
+    pub max_results: Option<u32>,
+}
+
+/// Result of `diffDatabases()`
+#[napi(object)]
+#[derive(Debug, Clone, Default)]
+pub struct DatabaseDiff {
+    /// Number of local keys compared
+    pub scanned: i64,
+
+    /// Number of local keys missing on the other side
+    pub missing_count: i64,
+
+    /// Number of keys that only exist on the other side
+    pub extra_count: i64,
+
+    /// Number of keys whose values differ
+    pub different_count: i64,
+
+    /// Local keys missing on the other side
+    pub missing: Vec<String>,
+
+    /// Keys that only exist on the other side
+    pub extra: Vec<String>,
+
+    /// Keys whose values differ
+    pub different: Vec<String>,
+}
+
+/// Progress and result of `importRespFile()`
+#[napi(object)]
+#[derive(Debug, Clone, Default)]
+pub struct RespImportReport {
+    /// Number of commands sent so far
+    pub commands: i64,
+
+    /// Number of commands that returned an error reply
+    pub failed: i64,
+
+    /// Error replies with the 1-based command number (at most 100 are kept)
+    pub errors: Vec<String>,
+}
+
+/// Kind of a presence change
+#[napi]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PresenceEventKind {
+    /// The member came online
+    Join,
+    /// The member left or timed out
+    Leave,
+}
+
+/// Presence change delivered by `PresenceTracker.watch()`
+#[napi(object)]
+#[derive(Debug, Clone)]
+pub struct PresenceEvent {
+    /// Member id
+    pub member: String,
+
+    /// Whether the member joined or left
+    pub kind: PresenceEventKind,
+}
+
+/// Result of `onceWithinPayload()`
+#[napi(object)]
+#[derive(Debug, Clone)]
+pub struct OnceResult {
+    /// Whether this caller claimed the window
+    pub won: bool,
+
+    /// Payload stored by the winner of the current window
+    pub payload: Option<String>,
+}
+
+/// Type of an entity field registered with `registerSchema()`
+#[napi]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EntityFieldType {
+    /// Stored as-is
+    String,
+    /// Floating point number
+    Number,
+    /// Integer number
+    Integer,
+    /// Stored as "1" / "0"
+    Boolean,
+    /// Any JSON value, stored serialized
+    Json,
+}
+
+/// A field of an entity schema
+#[napi(object)]
+#[derive(Debug, Clone)]
+pub struct EntityField {
+    /// Field name in both the ArkTS object and the hash
+    pub name: String,
+
+    /// Field type used for conversion
+    #[napi(js_name = "type")]
+    pub field_type: EntityFieldType,
+}
+
+/// A sorted set member with its score
+#[napi(object)]
+#[derive(Debug, Clone)]
+pub struct ScoredMember {
+    /// Member name
+    pub member: String,
+
+    /// Member score
+    pub score: f64,
+}
+
+/// Fields of one hash to expire in `hexpireBatch()`
+#[napi(object)]
+#[derive(Debug, Clone)]
+pub struct HexpireBatchEntry {
+    /// The hash key
+    pub key: String,
+
+    /// Fields to set the expiration on
+    pub fields: Vec<String>,
+
+    /// Expiration time in seconds
+    pub ttl: i64,
+}
+
+/// Outcome of `hexpireBatch()` for a single field
+#[napi(object)]
+#[derive(Debug, Clone)]
+pub struct HexpireFieldResult {
+    /// The hash key
+    pub key: String,
+
+    /// The field name
+    pub field: String,
+
+    /// What HEXPIRE did with the field
+    pub result: RedisExpireResult,
+}
+
+/// A bucket of the latency histogram in `ConnectionMetrics`
+#[napi(object)]
+#[derive(Debug, Clone)]
+pub struct LatencyBucket {
+    /// Round trips in this bucket took less than this many milliseconds
+    pub upper_bound_ms: f64,
+
+    /// Number of round trips in this bucket
+    pub count: i64,
+}
+
+/// Request metrics of a connection
+///
+/// A pipeline counts as one round trip for latency but as all of its
+/// commands for `commands`. Percentiles are the upper bound of the histogram
+/// bucket they fall into (buckets double in width).
+///
+/// # Example (ArkTS)
+/// ```typescript
+/// const m = conn.getMetrics();
+/// console.log(`${m.commands} cmds, ${m.errors} errors, p99 < ${m.latencyP99Ms} ms`);
+/// ```
+#[napi(object)]
+#[derive(Debug, Clone)]
+pub struct ConnectionMetrics {
+    /// Commands sent
+    pub commands: i64,
+
+    /// Failed round trips plus error replies
+    pub errors: i64,
+
+    /// Bytes written to the server
+    pub bytes_out: i64,
+
+    /// Approximate bytes read from the server
+    pub bytes_in: i64,
+
+    /// Median round trip latency in milliseconds
+    pub latency_p50_ms: f64,
+
+    /// 95th percentile round trip latency in milliseconds
+    pub latency_p95_ms: f64,
+
+    /// 99th percentile round trip latency in milliseconds
+    pub latency_p99_ms: f64,
+
+    /// Non-empty latency buckets, fastest first
+    pub latency_histogram: Vec<LatencyBucket>,
+}
+
+/// Details of a panic in native code
+#[napi(object)]
+#[derive(Debug, Clone)]
+pub struct PanicInfo {
+    /// Panic message
+    pub message: String,
+
+    /// Source location ("file:line:column"), if known
+    pub location: Option<String>,
+
+    /// Name of the panicking thread, if it has one
+    pub thread: Option<String>,
+
+    /// Captured backtrace
+    pub backtrace: String,
+
+    /// When the panic happened (Unix time in milliseconds)
+    pub timestamp_ms: i64,
+}
+
+/// End of a list for LMOVE / BLMOVE
+#[napi]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ListDirection {
+    /// The head of the list
+    Left,
+    /// The tail of the list
+    Right,
+}
+
+/// Element popped by BLPOP / BRPOP
+#[napi(object)]
+#[derive(Debug, Clone)]
+pub struct BlockingPopResult {
+    /// The list the element was popped from
+    pub key: String,
+
+    /// The popped element
+    pub value: String,
+}
+
+/// Member popped by BZPOPMIN
+#[napi(object)]
+#[derive(Debug, Clone)]
+pub struct BlockingZsetPopResult {
+    /// The sorted set the member was popped from
+    pub key: String,
+
+    /// The popped member
+    pub member: String,
+
+    /// Its score
+    pub score: f64,
+}
+
+/// Keyspace event delivered by `watchKeyEvents()`
+#[napi(object)]
+#[derive(Debug, Clone)]
+pub struct KeyEvent {
+    /// The key the event happened on
+    pub key: String,
+
+    /// Event name as sent by Redis (e.g., "set", "del", "expired", "hset")
+    pub event: String,
+
+    /// Database of the key
+    pub db: i64,
+}
+
+/// Options for `watchKeyEvents()`
+#[napi(object)]
+#[derive(Debug, Clone, Default)]
+pub struct KeyEventWatchOptions {
+    /// Enable the required `notify-keyspace-events` flags with CONFIG SET (default: true)
+    ///
+    /// Set to false on servers where CONFIG is disabled and notifications
+    /// are already configured.
+    pub configure_server: Option<bool>,
+}
+
+/// Server push frame delivered by `getPushConnection()`
+#[napi(object)]
+#[derive(Debug, Clone)]
+pub struct PushMessage {
+    /// Push kind (e.g., "invalidate", "message", "pmessage", "disconnection")
+    pub kind: String,
+
+    /// JSON array of the frame's payload
+    pub data: String,
+
+    /// Invalidated keys, for "invalidate" frames
+    ///
+    /// null when the whole client-side cache must be flushed (e.g., after FLUSHALL).
+    pub keys: Option<Vec<String>>,
+}
+
+/// Kind of a reply held in a `RedisValue`
+#[napi]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RedisValueKind {
+    /// Nil reply (no payload)
+    Nil,
+    /// Integer reply (`int`)
+    Int,
+    /// RESP3 double (`double`)
+    Double,
+    /// RESP3 boolean (`boolean`)
+    Boolean,
+    /// Bulk string (`string`, or `bytes` for binary replies)
+    BulkString,
+    /// Simple status string such as "QUEUED" (`string`)
+    SimpleString,
+    /// "OK" status (`string`)
+    Okay,
+    /// RESP3 big number, as decimal text (`string`)
+    BigNumber,
+    /// RESP3 verbatim string (`string`)
+    VerbatimString,
+    /// Error reply to one command of a batch (`string`)
+    Error,
+    /// Array reply (`elements`)
+    Array,
+    /// RESP3 set (`elements`)
+    Set,
+    /// RESP3 map (`entries`)
+    Map,
+    /// RESP3 push frame (`string` holds the push kind, `elements` the payload)
+    Push,
+    /// RESP3 attribute (`elements` holds the value, `entries` the attributes)
+    Attribute,
+}
+
+/// Reply returned by generic commands
+///
+/// `kind` tells which payload field is set; the others are undefined.
+///
+/// # Example (ArkTS)
+/// ```typescript
+/// const reply = conn.execute(["HGETALL", "user:1"]);
+/// if (reply.kind === RedisValueKind.Map) {
+///   for (const entry of reply.entries!) {
+///     console.log(entry.key.string, entry.value.string);
+///   }
+/// } else if (reply.kind === RedisValueKind.Array) {
+///   // RESP2 servers return hashes as flat arrays
+///   const fields = reply.elements!.map((e) => e.string);
+/// }
+/// ```
+///
+/// # Note
+/// Integers larger than 2^53 lose precision in ArkTS numbers.
+#[napi(object)]
+pub struct RedisValue {
+    /// Which kind of reply this is
+    pub kind: RedisValueKind,
+
+    /// Payload of Int replies
+    pub int: Option<i64>,
+
+    /// Payload of Double replies
+    pub double: Option<f64>,
+
+    /// Payload of Boolean replies
+    pub boolean: Option<bool>,
+
+    /// Payload of string-like replies and error messages
+    pub string: Option<String>,
+
+    /// Payload of bulk strings returned by binary variants (`executeBuffer()`)
+    pub bytes: Option<napi_ohos::bindgen_prelude::Buffer>,
+
+    /// Items of Array, Set and Push replies
+    pub elements: Option<Vec<RedisValue>>,
+
+    /// Entries of Map replies and attributes of Attribute replies
+    pub entries: Option<Vec<RedisValueEntry>>,
+}
+
+/// Key/value pair of a map reply
+#[napi(object)]
+pub struct RedisValueEntry {
+    /// Map key
+    pub key: RedisValue,
+
+    /// Value stored under the key
+    pub value: RedisValue,
+}
+
+impl RedisValue {
+    fn of(kind: RedisValueKind) -> Self {
+        RedisValue {
+            kind,
+            int: None,
+            double: None,
+            boolean: None,
+            string: None,
+            bytes: None,
+            elements: None,
+            entries: None,
+        }
+    }
+
+    fn text(kind: RedisValueKind, text: String) -> Self {
+        RedisValue {
+            string: Some(text),
+            ..Self::of(kind)
+        }
+    }
+
+    /// Convert a reply
+    ///
+    /// With `binary`, bulk strings are returned as bytes instead of being
+    /// decoded as UTF-8.
+    pub fn from_redis_value(value: &redis::Value, binary: bool) -> Self {
+        use redis::Value;
+
+        let list = |items: &[Value]| -> Vec<RedisValue> {
+            items.iter().map(|item| Self::from_redis_value(item, binary)).collect()
+        };
+        let map = |pairs: &[(Value, Value)]| -> Vec<RedisValueEntry> {
+            pairs
+                .iter()
+                .map(|(k, v)| RedisValueEntry {
+                    key: Self::from_redis_value(k, binary),
+                    value: Self::from_redis_value(v, binary),
+                })
+                .collect()
+        };
+
+        match value {
+            Value::Nil => Self::of(RedisValueKind::Nil),
+            Value::Int(i) => RedisValue {
+                int: Some(*i),
+                ..Self::of(RedisValueKind::Int)
+            },
+            Value::Double(f) => RedisValue {
+                double: Some(*f),
+                ..Self::of(RedisValueKind::Double)
+            },
+            Value::Boolean(b) => RedisValue {
+                boolean: Some(*b),
+                ..Self::of(RedisValueKind::Boolean)
+            },
+            Value::BulkString(bytes) if binary => RedisValue {
+                bytes: Some(bytes.clone().into()),
+                ..Self::of(RedisValueKind::BulkString)
+            },
+            Value::BulkString(bytes) => {
+                Self::text(RedisValueKind::BulkString, String::from_utf8_lossy(bytes).into_owned())
+            }
+            Value::SimpleString(s) => Self::text(RedisValueKind::SimpleString, s.clone()),
+            Value::Okay => Self::text(RedisValueKind::Okay, "OK".to_string()),
+            Value::BigNumber(n) => Self::text(RedisValueKind::BigNumber, n.to_string()),
+            Value::VerbatimString { format: _, text } => Self::text(RedisValueKind::VerbatimString, text.clone()),
+            Value::ServerError(err) => Self::text(RedisValueKind::Error, err.to_string()),
+            Value::Array(items) => RedisValue {
+                elements: Some(list(items)),
+                ..Self::of(RedisValueKind::Array)
+            },
+            Value::Set(items) => RedisValue {
+                elements: Some(list(items)),
+                ..Self::of(RedisValueKind::Set)
+            },
+            Value::Map(pairs) => RedisValue {
+                entries: Some(map(pairs)),
+                ..Self::of(RedisValueKind::Map)
+            },
+            Value::Push { kind, data } => RedisValue {
+                string: Some(kind.to_string()),
+                elements: Some(list(data)),
+                ..Self::of(RedisValueKind::Push)
+            },
+            Value::Attribute { data, attributes } => RedisValue {
+                elements: Some(vec![Self::from_redis_value(data, binary)]),
+                entries: Some(map(attributes)),
+                ..Self::of(RedisValueKind::Attribute)
+            },
+            // Handle any future variants that might be added
+            _ => Self::text(RedisValueKind::SimpleString, format!("{:?}", value)),
+        }
+    }
+}
+
+/// Options for `xadd()`
+///
+/// # Example (ArkTS)
+/// ```typescript
+/// // Keep roughly the latest 10000 readings
+/// conn.xadd("sensor:1", "*", [["temp", "23.5"]], { maxlen: 10000, approximate: true });
+///
+/// // Drop readings older than one hour
+/// conn.xadd("sensor:1", "*", [["temp", "23.5"]], { minid: `${Date.now() - 3600000}-0` });
+/// ```
+#[napi(object)]
+#[derive(Debug, Clone, Default)]
+pub struct XAddOptions {
+    /// Trim the stream to at most this many entries (MAXLEN)
+    pub maxlen: Option<i64>,
+
+    /// Evict entries with IDs lower than this one (MINID)
+    pub minid: Option<String>,
+
+    /// Trim with `~`, letting Redis keep a few extra entries to trim whole
+    /// macro nodes (default: false)
+    pub approximate: Option<bool>,
+
+    /// Maximum entries evicted by this call (LIMIT); requires `approximate`
+    pub limit: Option<i64>,
+
+    /// Don't create the stream if it doesn't exist (NOMKSTREAM)
+    pub nomkstream: Option<bool>,
+}
+
+/// Entry of a stream, as returned by `xrange()` and the other stream readers
+#[napi(object)]
+#[derive(Debug, Clone)]
+pub struct StreamEntry {
+    /// Entry ID (e.g., "1700000000000-0")
+    pub id: String,
+
+    /// Field/value pairs of the entry
+    pub fields: std::collections::HashMap<String, String>,
+}
+
+impl From<(String, Vec<(String, String)>)> for StreamEntry {
+    fn from((id, fields): (String, Vec<(String, String)>)) -> Self {
+        StreamEntry {
+            id,
+            fields: fields.into_iter().collect(),
+        }
+    }
+}
+
+/// Entries read from one stream by `xread()` or `xreadgroup()`
+#[napi(object)]
+#[derive(Debug, Clone)]
+pub struct StreamReadResult {
+    /// The stream key
+    pub stream: String,
+
+    /// Entries read from it, oldest first
+    pub entries: Vec<StreamEntry>,
+}
+
+impl From<(String, Vec<(String, Vec<(String, String)>)>)> for StreamReadResult {
+    fn from((stream, entries): (String, Vec<(String, Vec<(String, String)>)>)) -> Self {
+        StreamReadResult {
+            stream,
+            entries: entries.into_iter().map(StreamEntry::from).collect(),
+        }
+    }
+}
+
+/// Summary of a consumer group's pending entries, returned by `xpending()`
+#[napi(object)]
+#[derive(Debug, Clone)]
+pub struct StreamPendingSummary {
+    /// Number of entries delivered but not yet acknowledged
+    pub count: i64,
+
+    /// Smallest pending ID, or null when nothing is pending
+    pub min_id: Option<String>,
+
+    /// Greatest pending ID, or null when nothing is pending
+    pub max_id: Option<String>,
+
+    /// Consumers that have pending entries
+    pub consumers: Vec<StreamPendingConsumer>,
+}
+
+/// Pending entry count of one consumer
+#[napi(object)]
+#[derive(Debug, Clone)]
+pub struct StreamPendingConsumer {
+    /// Consumer name
+    pub name: String,
+
+    /// Entries delivered to it and not yet acknowledged
+    pub pending: i64,
+}
+
+/// Pending entry, returned by `xpendingRange()`
+#[napi(object)]
+#[derive(Debug, Clone)]
+pub struct StreamPendingEntry {
+    /// Entry ID
+    pub id: String,
+
+    /// Consumer the entry was last delivered to
+    pub consumer: String,
+
+    /// Milliseconds since the entry was last delivered
+    pub idle_ms: i64,
+
+    /// Number of times the entry has been delivered
+    pub delivery_count: i64,
+}
+
+/// Result of `xautoclaim()`
+#[napi(object)]
+#[derive(Debug, Clone)]
+pub struct StreamAutoClaimResult {
+    /// ID to pass as `start` to continue scanning; "0-0" once the whole
+    /// pending list has been scanned
+    pub next_id: String,
+
+    /// Entries now owned by the claiming consumer
+    pub entries: Vec<StreamEntry>,
+
+    /// IDs that were pending but no longer exist in the stream; they are
+    /// removed from the pending list (Redis 7+)
+    pub deleted_ids: Vec<String>,
+}
+
+/// Stream metadata, returned by `xinfoStream()`
+#[napi(object)]
+#[derive(Debug, Clone)]
+pub struct StreamInfo {
+    /// Number of entries
+    pub length: i64,
+
+    /// Keys in the underlying radix tree
+    pub radix_tree_keys: i64,
+
+    /// Nodes in the underlying radix tree
+    pub radix_tree_nodes: i64,
+
+    /// Number of consumer groups
+    pub groups: i64,
+
+    /// ID of the most recently added entry
+    pub last_generated_id: String,
+
+    /// Greatest ID deleted from the stream (Redis 7+)
+    pub max_deleted_entry_id: Option<String>,
+
+    /// Total entries ever added, including deleted ones (Redis 7+)
+    pub entries_added: Option<i64>,
+
+    /// ID of the first entry (Redis 7+)
+    pub recorded_first_entry_id: Option<String>,
+
+    /// First entry, or null if the stream is empty
+    pub first_entry: Option<StreamEntry>,
+
+    /// Last entry, or null if the stream is empty
+    pub last_entry: Option<StreamEntry>,
+}
+
+/// Consumer group metadata, returned by `xinfoGroups()`
+#[napi(object)]
+#[derive(Debug, Clone)]
+pub struct StreamGroupInfo {
+    /// Group name
+    pub name: String,
+
+    /// Number of consumers in the group
+    pub consumers: i64,
+
+    /// Entries delivered but not yet acknowledged
+    pub pending: i64,
+
+    /// ID of the last entry delivered to the group
+    pub last_delivered_id: String,
+
+    /// Logical read counter of the group (Redis 7+)
+    pub entries_read: Option<i64>,
+
+    /// Entries not yet delivered to the group (Redis 7+); null when it
+    /// can't be computed
+    pub lag: Option<i64>,
+}
+
+/// Consumer metadata, returned by `xinfoConsumers()`
+#[napi(object)]
+#[derive(Debug, Clone)]
+pub struct StreamConsumerInfo {
+    /// Consumer name
+    pub name: String,
+
+    /// Entries delivered to it and not yet acknowledged
+    pub pending: i64,
+
+    /// Milliseconds since its last attempted interaction (read, claim...)
+    pub idle_ms: i64,
+
+    /// Milliseconds since its last successful interaction (Redis 7.2+);
+    /// -1 if it never had one
+    pub inactive_ms: Option<i64>,
+}
+
+/// Trimming strategy for `xtrimWith()`
+///
+/// Exactly one of `maxlen` and `minid` must be set.
+#[napi(object)]
+#[derive(Debug, Clone, Default)]
+pub struct XTrimOptions {
+    /// Keep at most this many entries (MAXLEN)
+    pub maxlen: Option<i64>,
+
+    /// Evict entries with IDs lower than this one (MINID)
+    pub minid: Option<String>,
+
+    /// Trim with `~`, letting Redis keep a few extra entries to trim whole
+    /// macro nodes (default: false)
+    pub approximate: Option<bool>,
+
+    /// Maximum entries evicted by this call (LIMIT); requires `approximate`
+    pub limit: Option<i64>,
+}
+
+impl From<&XAddOptions> for XTrimOptions {
+    fn from(options: &XAddOptions) -> Self {
+        XTrimOptions {
+            maxlen: options.maxlen,
+            minid: options.minid.clone(),
+            approximate: options.approximate,
+            limit: options.limit,
+        }
+    }
+}
+
+/// Options for `RedisClient.streamConsumer()`
+#[napi(object)]
+#[derive(Debug, Clone, Default)]
+pub struct StreamConsumerOptions {
+    /// Maximum entries fetched per XREADGROUP (default: 10)
+    pub count: Option<u32>,
+
+    /// How long each XREADGROUP waits for new entries, in milliseconds (default: 2000)
+    ///
+    /// Also bounds how long `stop()` may wait for the worker to notice.
+    pub block_ms: Option<u32>,
+
+    /// Create the group (and the stream) if it doesn't exist (default: true)
+    pub create_group: Option<bool>,
+
+    /// ID the group starts from when it is created (default: "$", new entries only)
+    pub start_id: Option<String>,
+
+    /// Redeliver this consumer's unacknowledged entries once before reading
+    /// new ones, e.g. after a crash (default: true)
+    pub recover_pending: Option<bool>,
+}
+
+/// Configuration for `RedisClusterClient.fromConfig()`
+///
+/// # Example (ArkTS)
+/// ```typescript
+/// const cluster = RedisClusterClient.fromConfig({
+///   nodes: ["redis://10.0.0.1:7000", "redis://10.0.0.2:7000"],
+///   password: "secret",
+///   connectionTimeoutMs: 3000,
+/// });
+/// ```
+#[napi(object)]
+#[derive(Debug, Clone, Default)]
+pub struct RedisClusterConfig {
+    /// URLs of one or more cluster nodes used to discover the topology
+    pub nodes: Vec<String>,
+
+    /// Username for authentication on every node (Redis 6.0+)
+    pub username: Option<String>,
+
+    /// Password for authentication on every node
+    pub password: Option<String>,
+
+    /// Connection timeout per node in milliseconds (default: no timeout)
+    pub connection_timeout_ms: Option<u32>,
+
+    /// How long commands wait for a reply, in milliseconds (default: no timeout)
+    pub response_timeout_ms: Option<u32>,
+
+    /// Retries on MOVED, ASK, TRYAGAIN and connection errors (default: 16)
+    pub retries: Option<u32>,
+
+    /// Which nodes serve read-only commands (default: "master")
+    pub read_from: Option<ClusterReadFrom>,
+}
+
+/// Routing policy for read-only commands on a cluster
+///
+/// Writes always go to the master of the slot. Reads from replicas may
+/// return slightly stale data, as replication is asynchronous. With
+/// "nearest", every node's round trip is measured when the connection is
+/// created and again once a minute.
+///
+/// # Example (ArkTS)
+/// ```typescript
+/// const cluster = RedisClusterClient.fromConfig({
+///   nodes: ["redis://10.0.0.1:7000", "redis://10.0.0.2:7000"],
+///   readFrom: "replica"
+/// });
+/// ```
+#[napi(string_enum = "lowercase")]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ClusterReadFrom {
+    /// Read from the master of the slot
+    Master,
+    /// Read from a replica of the slot, or from the master if it has none
+    Replica,
+    /// Read from the master or replica of the slot with the lowest PING
+    /// round trip. Applies to the single-key read methods (get, hget,
+    /// hgetall, lrange, smembers, exists, ttl); other reads go to a replica
+    Nearest,
+}
+
+/// Address family used to reach the server
+///
+/// Sync TLS connections always use the system resolver.
+///
+/// # Example (ArkTS)
+/// ```typescript
+/// // The Wi-Fi network advertises IPv6 but doesn't route it
+/// const client = RedisClient.fromConfig({ host: "redis.example.com", ipFamily: "ipv4" });
+/// ```
+#[napi(string_enum = "lowercase")]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum IpFamily {
+    /// Any address, in the order returned by the resolver
+    Any,
+    /// IPv4 addresses only
+    Ipv4,
+    /// IPv6 addresses only
+    Ipv6,
+}
+
+impl IpFamily {
+    /// Name used in error messages
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            IpFamily::Any => "IP",
+            IpFamily::Ipv4 => "IPv4",
+            IpFamily::Ipv6 => "IPv6",
+        }
+    }
+}
+
+/// Range of hash slots served by a cluster node (bounds included)
+#[napi(object)]
+#[derive(Debug, Clone)]
+pub struct ClusterSlotRange {
+    pub start: u32,
+    pub end: u32,
+}
+
+/// One node of a CLUSTER NODES reply
+#[napi(object)]
+#[derive(Debug, Clone)]
+pub struct ClusterNode {
+    /// Node ID
+    pub id: String,
+
+    /// Address clients connect to; empty if the node's address is unknown
+    pub host: String,
+
+    pub port: u32,
+
+    /// Cluster bus port
+    pub bus_port: Option<u32>,
+
+    /// Announced hostname (Redis 7.0+)
+    pub hostname: Option<String>,
+
+    /// Flags, e.g. "myself", "master", "slave", "fail?", "fail"
+    pub flags: Vec<String>,
+
+    /// ID of the master, for replicas
+    pub master_id: Option<String>,
+
+    /// Milliseconds timestamp of the last pending PING, 0 if none
+    pub ping_sent: i64,
+
+    /// Milliseconds timestamp of the last PONG received
+    pub pong_recv: i64,
+
+    pub config_epoch: i64,
+
+    /// "connected" or "disconnected"
+    pub link_state: String,
+
+    /// Slots served by the node, for masters
+    pub slots: Vec<ClusterSlotRange>,
+}
+
+/// Node serving a slot range of a CLUSTER SLOTS reply
+#[napi(object)]
+#[derive(Debug, Clone)]
+pub struct ClusterSlotNode {
+    /// Preferred endpoint; empty if the node's address is unknown
+    pub host: String,
+
+    pub port: u32,
+
+    /// Node ID (Redis 4.0+)
+    pub id: Option<String>,
+}
+
+/// Slot range of a CLUSTER SLOTS reply with the nodes serving it
+#[napi(object)]
+#[derive(Debug, Clone)]
+pub struct ClusterSlotInfo {
+    /// First slot of the range
+    pub start_slot: u32,
+
+    /// Last slot of the range (included)
+    pub end_slot: u32,
+
+    pub master: ClusterSlotNode,
+
+    pub replicas: Vec<ClusterSlotNode>,
+}
+
+/// Subcommand of CLUSTER SETSLOT
+#[napi]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ClusterSlotState {
+    /// The slot is being moved to this node from the given node
+    Importing,
+    /// The slot is being moved from this node to the given node
+    Migrating,
+    /// Assign the slot to the given node
+    Node,
+    /// Clear the importing or migrating state of the slot
+    Stable,
+}
+
+/// Option of CLUSTER FAILOVER
+#[napi]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ClusterFailoverMode {
+    /// Don't wait for the master to hand over its replication offset;
+    /// still needs the agreement of the other masters
+    Force,
+    /// Promote the replica without the agreement of the other masters
+    Takeover,
+}
+
+/// Configuration of a RedisSentinelClient
+///
+/// # Example (ArkTS)
+/// ```typescript
+/// const sentinel = RedisSentinelClient.fromConfig({
+///   sentinels: ["redis://10.0.0.1:26379", "redis://10.0.0.2:26379"],
+///   serviceName: "mymaster",
+///   password: "secret",
+///   autoFailover: true
+/// });
+/// ```
+#[napi(object)]
+#[derive(Debug, Clone, Default)]
+pub struct RedisSentinelConfig {
+    /// URLs of the sentinel nodes, with their own credentials if needed
+    pub sentinels: Vec<String>,
+
+    /// Name of the monitored master
+    pub service_name: String,
+
+    /// Database index on the master (default: 0)
+    pub db: Option<i32>,
+
+    /// Username for authentication on the master (Redis 6.0+)
+    pub username: Option<String>,
+
+    /// Password for authentication on the master
+    pub password: Option<String>,
+
+    /// Let connections switch to the new master after a failover
+    /// (default: false)
+    pub auto_failover: Option<bool>,
+}
+
+/// Master switch announced by Sentinel on `+switch-master`
+#[napi(object)]
+#[derive(Debug, Clone)]
+pub struct SentinelFailoverEvent {
+    /// Name of the monitored master
+    pub service_name: String,
+
+    /// Address of the previous master, as "host:port"
+    pub old_master: String,
+
+    /// Address of the new master, as "host:port"
+    pub new_master: String,
+}
+
+/// How the text of an EncodedValue is encoded
+#[napi(string_enum = "lowercase")]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ValueEncoding {
+    /// The value is valid UTF-8 and returned as is
+    Utf8,
+    /// The value is binary and returned base64-encoded (standard alphabet, padded)
+    Base64,
+}
+
+/// A value that is returned as text even when it is binary
+#[napi(object)]
+#[derive(Debug, Clone)]
+pub struct EncodedValue {
+    /// The value, or its base64 encoding if it is not valid UTF-8
+    pub value: String,
+
+    /// How `value` is encoded
+    pub encoding: ValueEncoding,
+}
+
+/// Options for `setWithOptions()`
+///
+/// At most one of `ex`, `px`, `exat`, `pxat` and `keepttl` can be set, and
+/// not both `nx` and `xx`.
+///
+/// # Example (ArkTS)
+/// ```typescript
+/// // Take a lock for 30 seconds unless someone holds it
+/// const r = conn.setWithOptions("lock:job", token, { nx: true, ex: 30 });
+///
+/// // Swap in a new value, keeping the TTL, and get the old one
+/// const old = conn.setWithOptions("config", json, { keepttl: true, get: true }).previous;
+/// ```
+#[napi(object)]
+#[derive(Debug, Clone, Default)]
+pub struct SetOptions {
+    /// Expire after this many seconds (EX)
+    pub ex: Option<u32>,
+
+    /// Expire after this many milliseconds (PX)
+    pub px: Option<i64>,
+
+    /// Expire at this Unix time in seconds (EXAT)
+    pub exat: Option<i64>,
+
+    /// Expire at this Unix time in milliseconds (PXAT)
+    pub pxat: Option<i64>,
+
+    /// Only set the key if it doesn't exist (NX)
+    pub nx: Option<bool>,
+
+    /// Only set the key if it already exists (XX)
+    pub xx: Option<bool>,
+
+    /// Keep the current TTL of the key (KEEPTTL)
+    pub keepttl: Option<bool>,
+
+    /// Return the previous value (GET, Redis 6.2+)
+    pub get: Option<bool>,
+}
+
+/// Outcome of `setWithOptions()`
+#[napi(object)]
+#[derive(Debug, Clone)]
+pub struct SetResult {
+    /// Whether the value was written (false if NX or XX prevented it)
+    pub applied: bool,
+
+    /// The previous value, with the `get` option; null if the key didn't
+    /// exist or `get` wasn't set
+    pub previous: Option<String>,
+}
+
+/// Options for `lcs()`
+///
+/// # Example (ArkTS)
+/// ```typescript
+/// // Positions of the common runs of at least 4 bytes
+/// const r = conn.lcs("draft:v1", "draft:v2", { idx: true, minMatchLen: 4, withMatchLen: true });
+/// ```
+#[napi(object)]
+#[derive(Debug, Clone, Default)]
+pub struct LcsOptions {
+    /// Only return the length of the common subsequence (LEN)
+    pub len: Option<bool>,
+
+    /// Return the positions of the matching runs instead of the subsequence (IDX)
+    pub idx: Option<bool>,
+
+    /// Skip matching runs shorter than this (MINMATCHLEN); requires `idx`
+    pub min_match_len: Option<u32>,
+
+    /// Include the length of each matching run (WITHMATCHLEN); requires `idx`
+    pub with_match_len: Option<bool>,
+}
+
+/// A run of the common subsequence found in both strings (byte offsets, inclusive)
+#[napi(object)]
+#[derive(Debug, Clone)]
+pub struct LcsMatch {
+    /// First byte of the run in the first key
+    pub key1_start: i64,
+
+    /// Last byte of the run in the first key
+    pub key1_end: i64,
+
+    /// First byte of the run in the second key
+    pub key2_start: i64,
+
+    /// Last byte of the run in the second key
+    pub key2_end: i64,
+
+    /// Length of the run, with `withMatchLen`
+    pub len: Option<i64>,
+}
+
+/// Result of `lcs()`
+#[napi(object)]
+#[derive(Debug, Clone)]
+pub struct LcsResult {
+    /// The longest common subsequence; null with `len` or `idx`
+    pub sequence: Option<String>,
+
+    /// Length of the longest common subsequence in bytes
+    pub len: i64,
+
+    /// Matching runs, from the last to the first, with `idx`
+    pub matches: Option<Vec<LcsMatch>>,
+}
+
+/// A key to set with its own expiration in `msetWithTtl()`
+#[napi(object)]
+#[derive(Debug, Clone)]
+pub struct TtlEntry {
+    /// The key to set
+    pub key: String,
+
+    /// The value to set
+    pub value: String,
+
+    /// Expiration time in seconds
+    pub ttl_seconds: u32,
+}